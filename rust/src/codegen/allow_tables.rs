@@ -0,0 +1,37 @@
+//! `allow_tables_to_appear_in_same_query!` generation. Diesel requires this
+//! declaration before a query can span two tables joined via `joinable!`;
+//! without it, hand-written joins over generated tables won't compile.
+
+/// One `allow_tables_to_appear_in_same_query!` per FK-connected component,
+/// so Diesel only generates pairwise join impls for tables that could ever
+/// actually appear in the same query together. Falls back to a single
+/// global invocation when `single_group` is set, for callers who cross
+/// components with manual joins and would rather not split at all.
+pub fn render_allow_tables_by_component(
+    tables: &[String],
+    edges: &[(String, String)],
+    single_group: bool,
+) -> String {
+    if single_group {
+        return render_allow_tables(tables);
+    }
+    crate::codegen::components::compute_components(tables, edges)
+        .iter()
+        .map(|component| render_allow_tables(component))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// One `allow_tables_to_appear_in_same_query!(...)` invocation listing every
+/// table name given, in the planetwars/Lemmy generated-schema convention.
+pub fn render_allow_tables(tables: &[String]) -> String {
+    if tables.is_empty() {
+        return String::new();
+    }
+    let body = tables
+        .iter()
+        .map(|t| format!("    {t},"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!("diesel::allow_tables_to_appear_in_same_query!(\n{body}\n);\n")
+}
@@ -0,0 +1,103 @@
+//! `Identifiable` + `Associations`/`belongs_to` generation. A struct with a
+//! `<parent>_id` field gets one `#[diesel(belongs_to(Parent))]` per such
+//! field, mirroring the bvplan/fatcat schemas where every child row derives
+//! `Associations` for each of its parents (e.g. `OrderItem` belongs to both
+//! `Order` and `Product`).
+
+use crate::RustStruct;
+
+/// One `belongs_to(Parent)` relationship discovered on a struct.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BelongsTo {
+    pub parent: String,
+    pub foreign_key: String,
+}
+
+/// Find every `<parent>_id` field on `s` and resolve it to the struct name
+/// Diesel's `belongs_to` should reference. `New*` insert structs are skipped
+/// since `Identifiable`/`Associations` only apply to the `Queryable` row.
+pub fn discover_belongs_to(s: &RustStruct) -> Vec<BelongsTo> {
+    if s.name.starts_with("New") {
+        return Vec::new();
+    }
+    s.fields
+        .iter()
+        .filter_map(|f| {
+            let parent = f.name.strip_suffix("_id")?;
+            Some(BelongsTo {
+                parent: to_pascal_case(parent),
+                foreign_key: f.name.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Whether `relations` has a self-referential edge: a `<field>_id` pointing
+/// back at `s` itself (e.g. `parent_id` on a `Category` tree), the shape
+/// `joinable!` can't express without an explicit self-join alias.
+pub fn self_referential(s: &RustStruct, relations: &[BelongsTo]) -> bool {
+    relations.iter().any(|r| r.parent == s.name || r.foreign_key == "parent_id")
+}
+
+/// Whether more than one FK field resolves to the same parent: a composite
+/// foreign key made of two columns, which `joinable!` can't express since it
+/// only ever takes a single column.
+pub fn composite_foreign_key(relations: &[BelongsTo]) -> bool {
+    let mut parents: Vec<&str> = relations.iter().map(|r| r.parent.as_str()).collect();
+    parents.sort_unstable();
+    parents.windows(2).any(|w| w[0] == w[1])
+}
+
+/// `diesel::alias!` plus the join plumbing needed to self-join a table,
+/// since `joinable!(t -> t (fk))` can't express "join this table to itself".
+pub fn render_self_join_alias(table: &str) -> String {
+    format!(
+        "diesel::alias!({table} as {table}_parent: {table}ParentAlias);\n\n\
+         // Self-join: `{table}::table.inner_join({table}_parent.on({table}_parent.field({table}::id).eq({table}::parent_id)))`\n",
+    )
+}
+
+/// A documented `on(...)` join expression for a composite FK, since
+/// `joinable!` only ever takes a single column and would silently reference
+/// the wrong one if forced onto one of the pair.
+pub fn render_composite_join_helper(table: &str, parent_table: &str, columns: &[String]) -> String {
+    let mut predicate = format!("{table}::{}.eq({parent_table}::{})", columns[0], columns[0]);
+    for column in &columns[1..] {
+        predicate = format!("{predicate}.and({table}::{column}.eq({parent_table}::{column}))");
+    }
+    format!(
+        "// Composite FK: {table} -> {parent_table} over {columns:?} can't be expressed\n\
+         // with `joinable!`, which only takes one column. Join explicitly instead:\n\
+         // {table}::table.inner_join({parent_table}::table.on({predicate}));\n",
+    )
+}
+
+/// Render the `#[derive(Identifiable, Associations)]` header and one
+/// `#[diesel(belongs_to(Parent, foreign_key = fk))]` attribute per
+/// relationship, ready to splice above the existing `Queryable` derive.
+pub fn render_associations_header(relations: &[BelongsTo]) -> String {
+    if relations.is_empty() {
+        return String::new();
+    }
+    let mut out = String::from("#[derive(Identifiable, Associations)]\n");
+    for rel in relations {
+        out.push_str(&format!(
+            "#[diesel(belongs_to({}, foreign_key = {}))]\n",
+            rel.parent, rel.foreign_key
+        ));
+    }
+    out
+}
+
+fn to_pascal_case(snake: &str) -> String {
+    snake
+        .split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
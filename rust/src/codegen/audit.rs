@@ -0,0 +1,117 @@
+//! The `created_at`/`updated_at`/`created_by`/`updated_by` bundle that shows
+//! up verbatim on most generated tables (see `entity19s`/`entity38s`).
+//! Configurable so callers whose schema uses different column names or an
+//! actor type other than `Uuid` aren't stuck with the default, and factored
+//! into one `Audited` trait so the duplication doesn't have to be hand
+//! re-derived per struct.
+
+/// Names and SQL types for the four audit columns. Timestamps default to
+/// `Timestamptz` rather than `Timestamp` since audit columns are almost
+/// always meant to be timezone-aware.
+#[derive(Debug, Clone)]
+pub struct AuditColumns {
+    pub created_at: String,
+    pub updated_at: String,
+    pub created_by: String,
+    pub updated_by: String,
+    pub timestamp_sql_type: String,
+    pub actor_sql_type: String,
+}
+
+impl Default for AuditColumns {
+    fn default() -> Self {
+        AuditColumns {
+            created_at: "created_at".to_string(),
+            updated_at: "updated_at".to_string(),
+            created_by: "created_by".to_string(),
+            updated_by: "updated_by".to_string(),
+            timestamp_sql_type: "Timestamptz".to_string(),
+            actor_sql_type: "Uuid".to_string(),
+        }
+    }
+}
+
+/// The four `table!` column lines for this bundle, ready to splice into a
+/// generated table's column list.
+pub fn render_audit_table_columns(columns: &AuditColumns) -> String {
+    format!(
+        "    {} -> {},\n    {} -> {},\n    {} -> Nullable<{}>,\n    {} -> Nullable<{}>,\n",
+        columns.created_at, columns.timestamp_sql_type,
+        columns.updated_at, columns.timestamp_sql_type,
+        columns.created_by, columns.actor_sql_type,
+        columns.updated_by, columns.actor_sql_type,
+    )
+}
+
+/// The shared `Audited` trait every row struct carrying the bundle can
+/// implement once, instead of re-deriving four field accessors per table.
+/// `timestamp_ty` is the Rust type `columns.timestamp_sql_type` maps to via
+/// `models::sql_type_to_rust` (`chrono::NaiveDateTime` for `Timestamp`,
+/// `chrono::DateTime<chrono::Utc>` for `Timestamptz`), so the trait agrees
+/// with whatever the row struct's own fields were generated as.
+pub fn render_audited_trait(timestamp_ty: &str) -> String {
+    format!(
+        "pub trait Audited {{\n    fn created_at(&self) -> {ty};\n    fn updated_at(&self) -> {ty};\n    fn created_by(&self) -> Option<uuid::Uuid>;\n    fn updated_by(&self) -> Option<uuid::Uuid>;\n}}\n",
+        ty = timestamp_ty,
+    )
+}
+
+/// Whether `table` carries the audit bundle at all, by column name, so only
+/// entities that actually have it get an `Auditable` impl.
+pub fn supports_audit(table: &crate::DieselTable, columns: &AuditColumns) -> bool {
+    table.columns.iter().any(|c| c.name == columns.created_at)
+        && table.columns.iter().any(|c| c.name == columns.updated_at)
+        && table.columns.iter().any(|c| c.name == columns.created_by)
+        && table.columns.iter().any(|c| c.name == columns.updated_by)
+}
+
+/// The write-path counterpart to `Audited`: stamps `updated_at`/`updated_by`
+/// on the generated changeset before an update, and `created_by` on the
+/// `New*` insert struct, leaving DB-defaulted `created_at` untouched.
+pub fn render_auditable_trait() -> &'static str {
+    "pub trait Auditable {\n    \
+         fn stamp_for_insert(self, actor: uuid::Uuid) -> Self;\n    \
+         fn stamp_for_update(self, actor: uuid::Uuid) -> Self;\n\
+     }\n"
+}
+
+/// `impl Auditable for New{Struct}` / `{Struct}Changeset`, wired to the
+/// bundle's configured column names.
+pub fn render_auditable_impl(struct_name: &str, columns: &AuditColumns) -> String {
+    format!(
+        "impl Auditable for New{struct_name} {{\n    \
+             fn stamp_for_insert(mut self, actor: uuid::Uuid) -> Self {{\n        \
+                 self.{created_by} = Some(actor);\n        \
+                 self\n    \
+             }}\n\n    \
+             fn stamp_for_update(self, _actor: uuid::Uuid) -> Self {{\n        \
+                 self\n    \
+             }}\n\
+         }}\n\n\
+         impl Auditable for {struct_name}Changeset {{\n    \
+             fn stamp_for_insert(self, _actor: uuid::Uuid) -> Self {{\n        \
+                 self\n    \
+             }}\n\n    \
+             fn stamp_for_update(mut self, actor: uuid::Uuid) -> Self {{\n        \
+                 self.{updated_at} = Some(chrono::Utc::now());\n        \
+                 self.{updated_by} = Some(actor);\n        \
+                 self\n    \
+             }}\n\
+         }}\n",
+        created_by = columns.created_by,
+        updated_at = columns.updated_at,
+        updated_by = columns.updated_by,
+    )
+}
+
+/// `impl Audited for $struct` reading off the bundle's configured field names.
+/// `timestamp_ty` must be the same `models::sql_type_to_rust` mapping of
+/// `columns.timestamp_sql_type` passed to [`render_audited_trait`], since the
+/// accessor return types have to match the row struct's actual field types.
+pub fn render_audited_impl(struct_name: &str, columns: &AuditColumns, timestamp_ty: &str) -> String {
+    format!(
+        "impl Audited for {struct_name} {{\n    fn created_at(&self) -> {ty} {{ self.{} }}\n    fn updated_at(&self) -> {ty} {{ self.{} }}\n    fn created_by(&self) -> Option<uuid::Uuid> {{ self.{} }}\n    fn updated_by(&self) -> Option<uuid::Uuid> {{ self.{} }}\n}}\n",
+        columns.created_at, columns.updated_at, columns.created_by, columns.updated_by,
+        ty = timestamp_ty,
+    )
+}
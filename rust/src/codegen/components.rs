@@ -0,0 +1,109 @@
+//! Split `allow_tables_to_appear_in_same_query!` by FK-connected component
+//! instead of dumping every table into one invocation. Diesel expands that
+//! macro into a pairwise `AppearsInFromClause` impl per two tables listed,
+//! so a single call over 100+ tables is O(n^2) impls and can hit the
+//! compiler's type-length limit; tables with no FK path between them can
+//! never appear in the same query anyway, so they don't need to share one.
+
+use std::collections::HashMap;
+
+/// Union-find over table names, weighted by tree size for short paths.
+struct UnionFind {
+    parent: HashMap<String, String>,
+    size: HashMap<String, usize>,
+}
+
+impl UnionFind {
+    fn new(tables: &[String]) -> Self {
+        let mut parent = HashMap::new();
+        let mut size = HashMap::new();
+        for table in tables {
+            parent.insert(table.clone(), table.clone());
+            size.insert(table.clone(), 1);
+        }
+        UnionFind { parent, size }
+    }
+
+    fn find(&mut self, table: &str) -> String {
+        let next = self.parent.get(table).cloned().unwrap_or_else(|| table.to_string());
+        if next == table {
+            return next;
+        }
+        let root = self.find(&next);
+        self.parent.insert(table.to_string(), root.clone());
+        root
+    }
+
+    fn union(&mut self, a: &str, b: &str) {
+        let root_a = self.find(a);
+        let root_b = self.find(b);
+        if root_a == root_b {
+            return;
+        }
+        let (small, large) = if self.size[&root_a] < self.size[&root_b] {
+            (root_a, root_b)
+        } else {
+            (root_b, root_a)
+        };
+        *self.size.get_mut(&large).unwrap() += self.size[&small];
+        self.parent.insert(small, large);
+    }
+}
+
+/// Discover every (child_table, parent_table) FK edge from `belongs_to`
+/// relationships (resolved struct -> struct, then to table name via each
+/// struct's own `#[diesel(table_name = ...)]`) plus the two edges a join
+/// table implies. Shared by every caller that needs the FK graph rather
+/// than re-deriving it (splitting `allow_tables_to_appear_in_same_query!`,
+/// detecting multi-hop hierarchy chains, ...).
+pub fn discover_fk_edges(
+    tables: &[crate::DieselTable],
+    derives: &[crate::DieselDerive],
+) -> Vec<(String, String)> {
+    let table_name_of: HashMap<&str, &str> = derives
+        .iter()
+        .filter_map(|d| d.table_name.as_deref().map(|t| (d.struct_name.as_str(), t)))
+        .collect();
+
+    let mut edges: Vec<(String, String)> = Vec::new();
+    for derive in derives {
+        let Some(child_table) = derive.table_name.as_deref() else { continue };
+        for belongs_to in &derive.belongs_to {
+            if let Some(parent_table) = table_name_of.get(belongs_to.parent.as_str()) {
+                edges.push((child_table.to_string(), (*parent_table).to_string()));
+            }
+        }
+    }
+    for table in tables {
+        if let Some(join_edges) = super::join_tables::discover_join_table_edges(table) {
+            edges.push((join_edges.table.clone(), join_edges.left.parent_table));
+            edges.push((join_edges.table.clone(), join_edges.right.parent_table));
+        }
+    }
+    edges
+}
+
+/// Group `tables` into FK-connected components given the `joinable!` edges
+/// specql has discovered (via `belongs_to`/join-table detection). A table
+/// with no edges still comes back as its own singleton component.
+pub fn compute_components(tables: &[String], edges: &[(String, String)]) -> Vec<Vec<String>> {
+    let mut uf = UnionFind::new(tables);
+    for (a, b) in edges {
+        if uf.parent.contains_key(a) && uf.parent.contains_key(b) {
+            uf.union(a, b);
+        }
+    }
+
+    let mut groups: HashMap<String, Vec<String>> = HashMap::new();
+    for table in tables {
+        let root = uf.find(table);
+        groups.entry(root).or_default().push(table.clone());
+    }
+
+    let mut components: Vec<Vec<String>> = groups.into_values().collect();
+    for component in &mut components {
+        component.sort();
+    }
+    components.sort_by(|a, b| a.first().cmp(&b.first()));
+    components
+}
@@ -0,0 +1,102 @@
+//! Public-facing DTO layer, kept separate from the Diesel row structs so the
+//! persisted shape (raw ids, internal-only columns, `entityNN_id` naming)
+//! never leaks onto the JSON boundary, and so a schema change doesn't
+//! silently change the wire format. The `rename_all` casing is switched
+//! between camelCase (the default) and kebab-case by a cargo feature, the
+//! way the bazzar crate's API enums pin their own serde casing rather than
+//! inheriting whatever the DB-facing types derive.
+
+use crate::DieselTable;
+use crate::codegen::associations::BelongsTo;
+use crate::codegen::ids::IdNewType;
+use crate::codegen::models;
+
+/// Columns that exist for persistence bookkeeping and have no business on
+/// the public JSON contract.
+const INTERNAL_COLUMNS: &[&str] = &["metadata", "deleted_at", "created_by", "updated_by"];
+
+/// `#[serde(rename_all = ...)]`, switched between camelCase (the default)
+/// and kebab-case by the `dto-kebab-case` feature.
+pub fn render_dto_casing_attrs() -> &'static str {
+    "#[cfg_attr(not(feature = \"dto-kebab-case\"), serde(rename_all = \"camelCase\"))]\n\
+     #[cfg_attr(feature = \"dto-kebab-case\", serde(rename_all = \"kebab-case\"))]\n"
+}
+
+/// The field name a relation's FK column should surface as on the DTO:
+/// `parent` for a self-referential relation (the hierarchy case), otherwise
+/// the FK column with its `_id` suffix stripped (`entity08_id` -> `entity08`).
+fn dto_relation_field(table_struct_name: &str, relation: &BelongsTo) -> String {
+    if relation.parent == table_struct_name {
+        "parent".to_string()
+    } else {
+        relation.foreign_key.strip_suffix("_id").unwrap_or(&relation.foreign_key).to_string()
+    }
+}
+
+/// The `{Struct}Dto` struct: every column but [`INTERNAL_COLUMNS`], with FK
+/// columns renamed per [`dto_relation_field`] and, like `ids::render_retyped_struct`,
+/// retyped to their `Id` newtype via `known_ids` rather than left as a bare
+/// `i64` — the row struct's `From` impl assigns these fields directly, so
+/// the DTO's field type has to match. Casing left to
+/// [`render_dto_casing_attrs`].
+pub fn render_dto_struct(table: &DieselTable, relations: &[BelongsTo], known_ids: &[IdNewType]) -> String {
+    let struct_name = models::table_to_struct_name(&table.name);
+    let relation_by_fk: std::collections::HashMap<&str, &BelongsTo> =
+        relations.iter().map(|r| (r.foreign_key.as_str(), r)).collect();
+
+    let mut out = String::from("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
+    out.push_str(render_dto_casing_attrs());
+    out.push_str(&format!("pub struct {struct_name}Dto {{\n"));
+    for column in &table.columns {
+        if INTERNAL_COLUMNS.contains(&column.name.as_str()) {
+            continue;
+        }
+        let field_name = match relation_by_fk.get(column.name.as_str()) {
+            Some(relation) => dto_relation_field(&struct_name, relation),
+            None => column.name.clone(),
+        };
+        let retyped = if column.name == "id" {
+            known_ids
+                .iter()
+                .find(|id| id.name == format!("{struct_name}Id"))
+                .map(|id| id.name.clone())
+        } else {
+            crate::codegen::ids::resolve_fk_id_type(&column.name, known_ids)
+        };
+        let rust_type = match retyped {
+            Some(id_type) if column.is_nullable => format!("Option<{id_type}>"),
+            Some(id_type) => id_type,
+            None => models::sql_type_to_rust(&column.sql_type, column.is_nullable),
+        };
+        out.push_str(&format!("    pub {field_name}: {rust_type},\n"));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// `impl From<{Struct}> for {Struct}Dto`: drop the internal columns, rename
+/// relation fields the same way [`render_dto_struct`] did, and pass every
+/// remaining column through unchanged.
+pub fn render_dto_from_impl(table: &DieselTable, relations: &[BelongsTo]) -> String {
+    let struct_name = models::table_to_struct_name(&table.name);
+    let relation_by_fk: std::collections::HashMap<&str, &BelongsTo> =
+        relations.iter().map(|r| (r.foreign_key.as_str(), r)).collect();
+
+    let mut out = format!("impl From<{struct_name}> for {struct_name}Dto {{\n    fn from(row: {struct_name}) -> Self {{\n        Self {{\n");
+    for column in &table.columns {
+        if INTERNAL_COLUMNS.contains(&column.name.as_str()) {
+            continue;
+        }
+        let field_name = match relation_by_fk.get(column.name.as_str()) {
+            Some(relation) => dto_relation_field(&struct_name, relation),
+            None => column.name.clone(),
+        };
+        if field_name == column.name {
+            out.push_str(&format!("            {field_name}: row.{field_name},\n"));
+        } else {
+            out.push_str(&format!("            {field_name}: row.{},\n", column.name));
+        }
+    }
+    out.push_str("        }\n    }\n}\n");
+    out
+}
@@ -0,0 +1,40 @@
+//! Feature-gated fake-data derives, following the bazzar crate's
+//! `#[cfg_attr(feature = "dummy", derive(fake::Dummy))]` pattern so the
+//! `fake` dependency (and the derive output) only exists in builds that
+//! opt into the `dummy` feature for seeding or integration tests.
+
+use crate::DieselColumn;
+
+/// `#[cfg_attr(feature = "dummy", derive(fake::Dummy))]`, applied to every
+/// model and `New*` insert struct.
+pub fn render_dummy_derive_attr() -> &'static str {
+    "#[cfg_attr(feature = \"dummy\", derive(fake::Dummy))]\n"
+}
+
+/// A per-column `#[cfg_attr(feature = "dummy", dummy(...))]` constraint,
+/// where a bare derive would produce nonsense: a bounded range for a
+/// numeric `value` column, a uniform choice over `status_variants` for a
+/// `status` column, a real `Uuid` for `Uuid` columns, and a small JSON
+/// object for `metadata`. Returns `None` when the column needs no override.
+pub fn render_dummy_field_attr(column: &DieselColumn, status_variants: Option<&[String]>) -> Option<String> {
+    // Compared case-insensitively so both Diesel names (`Int8`, `Uuid`) and
+    // Postgres names (`int8`, `uuid`, as `TypeMap::resolve` returns) match.
+    let sql_type = column.sql_type.to_ascii_lowercase();
+    match column.name.as_str() {
+        "value" if matches!(sql_type.as_str(), "int2" | "int4" | "int8" | "float4" | "float8" | "numeric") => {
+            Some("#[cfg_attr(feature = \"dummy\", dummy(faker = \"0..1000\"))]\n".to_string())
+        }
+        "status" => status_variants.map(|variants| {
+            let choices = variants.iter().map(|v| format!("\"{v}\"")).collect::<Vec<_>>().join(", ");
+            format!("#[cfg_attr(feature = \"dummy\", dummy(faker = \"({choices})\"))]\n")
+        }),
+        "metadata" if sql_type == "jsonb" || sql_type == "json" => Some(
+            "#[cfg_attr(feature = \"dummy\", dummy(expr = \"serde_json::json!({\\\"note\\\": fake::faker::lorem::en::Word().fake::<String>()})\"))]\n"
+                .to_string(),
+        ),
+        _ if sql_type == "uuid" => {
+            Some("#[cfg_attr(feature = \"dummy\", dummy(faker = \"fake::uuid::UUIDv4\"))]\n".to_string())
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,131 @@
+//! A shared `Entity` trait plus a generic `Repository<E>` layer over every
+//! generated model, unifying the ~90 near-identical `EntityNN` types the way
+//! fatcat's `EntityIdentRow`/`EntityEditRow` traits unify its generated
+//! rows. `Entity` exposes the shape every model already has (`id`, `name`,
+//! `active`); `EntityStatus` is a second trait for the subset that also
+//! carries a `status` column, since not every model does. `Persisted` is the
+//! thin Diesel-backed half `Repository` dispatches through so it can stay
+//! generic without needing a `Table` associated type on `Entity` itself;
+//! it requires `SoftDelete` rather than reinventing soft-deletion, so there
+//! is exactly one `soft_delete` behavior per model.
+
+/// The shared accessors every generated model has.
+pub fn render_entity_trait() -> &'static str {
+    "pub trait Entity: Sized {\n    \
+         type Id;\n    \
+         type New;\n\n    \
+         fn id(&self) -> Self::Id;\n    \
+         fn name(&self) -> &str;\n    \
+         fn active(&self) -> bool;\n\
+     }\n\n\
+     /// Implemented in addition to [`Entity`] by models that also carry a\n\
+     /// status column.\n\
+     pub trait EntityStatus: Entity {\n    \
+         type Status;\n\n    \
+         fn status(&self) -> Self::Status;\n\
+     }\n"
+}
+
+/// `impl Entity for {name}`, and `impl EntityStatus for {name}` when the
+/// model carries a status column.
+pub fn render_entity_impl(struct_name: &str, has_status: bool) -> String {
+    let mut out = format!(
+        "impl Entity for {name} {{\n    \
+             type Id = {name}Id;\n    \
+             type New = New{name};\n\n    \
+             fn id(&self) -> Self::Id {{\n        \
+                 self.id\n    \
+             }}\n\n    \
+             fn name(&self) -> &str {{\n        \
+                 &self.name\n    \
+             }}\n\n    \
+             fn active(&self) -> bool {{\n        \
+                 self.active\n    \
+             }}\n\
+         }}\n",
+        name = struct_name,
+    );
+    if has_status {
+        out.push_str(&format!(
+            "\nimpl EntityStatus for {name} {{\n    \
+                 type Status = {name}Status;\n\n    \
+                 fn status(&self) -> Self::Status {{\n        \
+                     self.status\n    \
+                 }}\n\
+             }}\n",
+            name = struct_name,
+        ));
+    }
+    out
+}
+
+/// The Diesel-backed half of an [`Entity`] that `Repository` dispatches
+/// through, implemented once per model against its own table. `Persisted`
+/// itself only requires `Entity` as a supertrait — binding `SoftDelete<Id =
+/// Self::Id>` at the trait level as well would give `Self::Id` two
+/// supertraits each claiming to define it, an unresolvable cycle. Instead
+/// the `SoftDelete<Id = Self::Id>` bound lives on the `soft_delete` method
+/// alone, so `soft_delete` forwards to the `SoftDelete` impl every
+/// qualifying model already has (deleted_at IS NULL, not an `active` flag
+/// flip), and `Repository<E>::soft_delete` agrees with whatever `--emit
+/// soft-delete` generated.
+pub fn render_persisted_trait() -> &'static str {
+    "pub trait Persisted: Entity {\n    \
+         fn find_by_id(conn: &mut PgConnection, id: Self::Id) -> QueryResult<Self>;\n    \
+         fn list_active(conn: &mut PgConnection) -> QueryResult<Vec<Self>>;\n    \
+         fn insert(conn: &mut PgConnection, new: Self::New) -> QueryResult<Self>;\n\n    \
+         fn soft_delete(conn: &mut PgConnection, id: Self::Id) -> QueryResult<usize>\n    \
+         where\n        \
+             Self: SoftDelete<Id = Self::Id>,\n    \
+         {\n        \
+             <Self as SoftDelete>::soft_delete(conn, id)\n    \
+         }\n\
+     }\n"
+}
+
+/// `impl Persisted for {name}` against its own `{table}` module. `{name}`
+/// must already have a `SoftDelete` impl (see `codegen::soft_delete`) so the
+/// `where Self: SoftDelete<Id = Self::Id>` bound on `soft_delete` is
+/// satisfied — the method isn't redefined here, it inherits the trait's
+/// default forwarding body.
+pub fn render_persisted_impl(struct_name: &str, table: &str) -> String {
+    format!(
+        "impl Persisted for {name} {{\n    \
+             fn find_by_id(conn: &mut PgConnection, id: Self::Id) -> QueryResult<Self> {{\n        \
+                 {table}::table.find(id).first(conn)\n    \
+             }}\n\n    \
+             fn list_active(conn: &mut PgConnection) -> QueryResult<Vec<Self>> {{\n        \
+                 {table}::table.filter({table}::active.eq(true)).load(conn)\n    \
+             }}\n\n    \
+             fn insert(conn: &mut PgConnection, new: Self::New) -> QueryResult<Self> {{\n        \
+                 diesel::insert_into({table}::table).values(new).get_result(conn)\n    \
+             }}\n\
+         }}\n",
+        name = struct_name,
+        table = table,
+    )
+}
+
+/// The generic repository itself: a zero-sized, `PhantomData`-carrying
+/// handle that dispatches every operation through `E`'s own [`Persisted`]
+/// impl, so callers write `Repository::<Entity70>::find_by_id(conn, id)`
+/// once instead of hand-writing the same four queries per entity.
+pub fn render_repository() -> &'static str {
+    "pub struct Repository<E: Persisted> {\n    \
+         _marker: std::marker::PhantomData<E>,\n\
+     }\n\n\
+     impl<E: Persisted> Repository<E> {\n    \
+         pub fn find_by_id(conn: &mut PgConnection, id: E::Id) -> QueryResult<E> {\n        \
+             E::find_by_id(conn, id)\n    \
+         }\n\n    \
+         pub fn list_active(conn: &mut PgConnection) -> QueryResult<Vec<E>> {\n        \
+             E::list_active(conn)\n    \
+         }\n\n    \
+         pub fn insert(conn: &mut PgConnection, new: E::New) -> QueryResult<E> {\n        \
+             E::insert(conn, new)\n    \
+         }\n\n    \
+         pub fn soft_delete(conn: &mut PgConnection, id: E::Id) -> QueryResult<usize> {\n        \
+             E::soft_delete(conn, id)\n    \
+         }\n\
+     }\n"
+}
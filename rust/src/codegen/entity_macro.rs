@@ -0,0 +1,60 @@
+//! The `entity!` declarative macro (modeled on fatcat's `database_models.rs`
+//! macros for its edit/ident tables) that collapses a `Queryable`/
+//! `Insertable` pair plus the status enum into one invocation, so the
+//! generator can emit a single macro call per entity instead of hundreds of
+//! lines of copy-pasted, near-identical struct blocks. The status enum name
+//! is derived from `$name` via `paste!` rather than passed explicitly, so an
+//! invocation only ever names the things that actually vary between
+//! entities: the struct, its table, its extra columns, and its variants.
+
+/// The `macro_rules!` definition itself, emitted once into a shared module
+/// that every collapsed entity file then `use`s.
+pub fn render_macro_definition() -> &'static str {
+    "macro_rules! entity {\n    \
+         ($name:ident, $table:ident, { $($col:ident : $ty:ty),* $(,)? }, status: [$($variant:ident),+ $(,)?]) => {\n        \
+             paste::paste! {\n            \
+                 #[derive(Debug, Clone, Queryable, Insertable, Serialize, Deserialize)]\n                \
+                 #[diesel(table_name = $table)]\n                \
+                 pub struct $name {\n                    \
+                     pub id: i64,\n                    \
+                     $(pub $col: $ty,)*\n                    \
+                     pub status: [<$name Status>],\n                \
+                 }\n\n                \
+                 #[derive(Debug, Clone, Insertable)]\n                \
+                 #[diesel(table_name = $table)]\n                \
+                 pub struct [<New $name>] {\n                    \
+                     $(pub $col: $ty,)*\n                    \
+                     pub status: [<$name Status>],\n                \
+                 }\n\n                \
+                 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]\n                \
+                 pub enum [<$name Status>] {\n                    \
+                     $($variant,)+\n                \
+                 }\n            \
+             }\n        \
+         };\n    \
+     }\n"
+}
+
+/// Render one `entity!(...)` invocation for a single entity, given its
+/// extra columns (name, type) beyond the standard `id`/`status`, and its
+/// status enum's variant list.
+pub fn render_invocation(
+    name: &str,
+    table: &str,
+    columns: &[(&str, &str)],
+    status_variants: &[&str],
+) -> String {
+    let cols = columns
+        .iter()
+        .map(|(col, ty)| format!("{}: {}", col, ty))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let variants = status_variants.join(", ");
+    format!(
+        "entity!({name}, {table}, {{ {cols} }}, status: [{variants}]);",
+        name = name,
+        table = table,
+        cols = cols,
+        variants = variants,
+    )
+}
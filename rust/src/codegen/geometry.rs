@@ -0,0 +1,25 @@
+//! PostGIS spatial column support. Diesel's default `sql_types::*` doesn't
+//! cover `Point`/`Geometry`/`Geography`; those live in `diesel_geometry`, so
+//! a `table!` block referencing one needs an extra `use` line injected, or
+//! the column gets dropped or mistyped as `Text`.
+
+/// The Postgres geometry/geography column types this generator recognizes.
+const SPATIAL_SQL_TYPES: &[&str] = &["Point", "Geometry", "Geography"];
+
+/// Whether a column's (already `Nullable<...>`-unwrapped) SQL type is a
+/// PostGIS spatial type needing the `diesel_geometry` import.
+pub fn is_spatial_sql_type(sql_type: &str) -> bool {
+    SPATIAL_SQL_TYPES.contains(&sql_type)
+}
+
+/// The `use diesel_geometry::sql_types::*;` line a `table!` block needs
+/// once it references any spatial column, placed ahead of the column list
+/// per Diesel's convention for non-default SQL type imports.
+pub fn render_geometry_import() -> &'static str {
+    "use diesel_geometry::sql_types::*;\n"
+}
+
+/// Whether any column in `columns` needs the `diesel_geometry` import.
+pub fn table_needs_geometry_import(columns: &[(&str, &str)]) -> bool {
+    columns.iter().any(|(_, sql_type)| is_spatial_sql_type(sql_type))
+}
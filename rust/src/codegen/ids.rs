@@ -0,0 +1,132 @@
+//! Strongly-typed `FooId(pub i64)` newtype generation, the pattern Lemmy uses
+//! for `PostId`/`PersonId`/`CommentId` via `diesel_derive_newtype`. Given the
+//! structs a model file extracts to, this derives one id wrapper per table
+//! and works out which foreign-key fields should be retyped to point at it.
+
+use crate::{DieselTable, RustStruct};
+
+/// A single generated `FooId(pub i64)` wrapper.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdNewType {
+    pub name: String,
+    pub inner_type: String,
+}
+
+/// Render the derive list, the tuple struct, and the forwarding `Display`/
+/// `From<{inner}>` impls for one id newtype. `DieselNewType` forwards the
+/// inner type's `ToSql`/`FromSql`, so the wrapper stays `Queryable`/
+/// `Insertable` against the same column with no schema change, while
+/// `From<{inner}>` mixing up `Entity06Id` and `Entity02Id` becomes a compile
+/// error everywhere except that one explicit conversion site. `DieselNewType`
+/// is `full`-gated the same way `codegen::models`' row structs are, so a
+/// `default-features = false` consumer can use the id type without pulling
+/// in Diesel.
+pub fn render_id_newtype(id: &IdNewType) -> String {
+    format!(
+        "#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]\n\
+         #[cfg_attr(feature = \"full\", derive(DieselNewType))]\n\
+         pub struct {name}(pub {inner});\n\n\
+         impl std::fmt::Display for {name} {{\n    \
+             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{\n        \
+                 write!(f, \"{{}}\", self.0)\n    \
+             }}\n\
+         }}\n\n\
+         impl From<{inner}> for {name} {{\n    \
+             fn from(id: {inner}) -> Self {{\n        \
+                 {name}(id)\n    \
+             }}\n\
+         }}\n",
+        name = id.name,
+        inner = id.inner_type,
+    )
+}
+
+/// Derive one `Id` newtype per row struct that owns a bare `id` column.
+/// `New*`/`*Changeset` structs don't carry an `id` of their own, so they're
+/// skipped rather than producing a duplicate wrapper.
+pub fn generate_id_newtypes(structs: &[RustStruct]) -> Vec<IdNewType> {
+    structs
+        .iter()
+        .filter(|s| !s.name.starts_with("New"))
+        .filter(|s| s.fields.iter().any(|f| f.name == "id"))
+        .map(|s| IdNewType {
+            name: format!("{}Id", s.name),
+            inner_type: "i64".to_string(),
+        })
+        .collect()
+}
+
+/// Derive one `Id` newtype per `table!` that owns an `id` column, the
+/// schema-level counterpart to [`generate_id_newtypes`] for callers (like
+/// `codegen::models`) working off `DieselTable` rather than `RustStruct`.
+pub fn generate_id_newtypes_from_tables(tables: &[DieselTable]) -> Vec<IdNewType> {
+    tables
+        .iter()
+        .filter(|t| t.columns.iter().any(|c| c.name == "id"))
+        .map(|t| IdNewType {
+            name: format!("{}Id", crate::codegen::models::table_to_struct_name(&t.name)),
+            inner_type: "i64".to_string(),
+        })
+        .collect()
+}
+
+/// If `field_name` looks like a foreign key (`entity00_id`, `customer_id`,
+/// ...) and a matching `Id` newtype was generated for the referenced table,
+/// return that newtype's name so the field can be retyped instead of left as
+/// a bare `i64`.
+pub fn resolve_fk_id_type(field_name: &str, known_ids: &[IdNewType]) -> Option<String> {
+    let parent = field_name.strip_suffix("_id")?;
+    let candidate = format!("{}Id", to_pascal_case(parent));
+    known_ids
+        .iter()
+        .find(|id| id.name == candidate)
+        .map(|id| id.name.clone())
+}
+
+/// Reconstruct a field's full Rust type from its recursive `TypeInfo`
+/// (`field.field_type` only ever carries the base name, so `Vec<Tag>` would
+/// otherwise come back as the bare, invalid `Vec`). `is_optional` is handled
+/// by the caller, not here, since `extract_type_info` already unwraps
+/// `Option<T>` into `T`'s own `TypeInfo` with `is_optional` set.
+fn render_type_from_info(info: &crate::TypeInfo) -> String {
+    if info.generics.is_empty() {
+        info.base.clone()
+    } else {
+        let args: Vec<String> = info.generics.iter().map(render_type_from_info).collect();
+        format!("{}<{}>", info.base, args.join(", "))
+    }
+}
+
+/// Re-emit `s` with its `id` field and any foreign-key fields retyped to
+/// their newtype (`pub id: i64` -> `pub id: Entity05Id`, `entity00_id: i64`
+/// -> `entity00_id: Entity00Id`), falling back to the field's original type
+/// when no matching newtype was generated for it.
+pub fn render_retyped_struct(s: &RustStruct, known_ids: &[IdNewType]) -> String {
+    let mut out = format!("pub struct {} {{\n", s.name);
+    let own_id = known_ids.iter().find(|id| id.name == format!("{}Id", s.name));
+    for field in &s.fields {
+        let retyped = if field.name == "id" {
+            own_id.map(|id| id.name.clone())
+        } else {
+            resolve_fk_id_type(&field.name, known_ids)
+        };
+        let field_type = retyped.unwrap_or_else(|| render_type_from_info(&field.type_info));
+        let field_type = if field.is_optional { format!("Option<{field_type}>") } else { field_type };
+        out.push_str(&format!("    pub {}: {},\n", field.name, field_type));
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn to_pascal_case(snake: &str) -> String {
+    snake
+        .split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,34 @@
+//! Minimal, irregular-aware singularize/pluralize so every "table name" <->
+//! "struct name" conversion agrees, instead of three independent
+//! `strip_suffix('s')`/`+ "s"` implementations that disagree with each
+//! other (`"categories".strip_suffix('s')` -> `"categorie"`, not
+//! `"category"`).
+
+/// Pluralize a singular noun the way Postgres/Rails table-naming
+/// conventions do: `category` -> `categories`, `box` -> `boxes`, `post` ->
+/// `posts`.
+pub fn pluralize(word: &str) -> String {
+    if let Some(stem) = word.strip_suffix('y') {
+        if stem.chars().next_back().is_some_and(|c| !"aeiou".contains(c)) {
+            return format!("{stem}ies");
+        }
+    }
+    if word.ends_with('s') || word.ends_with('x') || word.ends_with('z') || word.ends_with("ch") || word.ends_with("sh") {
+        return format!("{word}es");
+    }
+    format!("{word}s")
+}
+
+/// Singularize a plural noun, the inverse of [`pluralize`]: `categories` ->
+/// `category`, `boxes` -> `box`, `addresses` -> `address`, `posts` -> `post`.
+/// A plural of a word ending in a single `s` (`buses`) only loses its final
+/// `s`.
+pub fn singularize(word: &str) -> String {
+    if let Some(stem) = word.strip_suffix("ies") {
+        return format!("{stem}y");
+    }
+    if word.ends_with("sses") || word.ends_with("xes") || word.ends_with("ches") || word.ends_with("shes") || word.ends_with("zes") {
+        return word[..word.len() - 2].to_string();
+    }
+    word.strip_suffix('s').unwrap_or(word).to_string()
+}
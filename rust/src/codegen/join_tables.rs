@@ -0,0 +1,76 @@
+//! Composite-primary-key and many-to-many join table support. A `table!`
+//! whose primary key spans two `_id` columns and has no other columns is a
+//! pure association table (e.g. `addresses_entities (address_id,
+//! entity_id)`); Diesel can't infer the two `joinable!` edges it implies, so
+//! we detect the shape and emit them alongside the composite-key table.
+
+use crate::DieselTable;
+
+/// A join table's two foreign-key edges, each pointing at the parent table
+/// the `_id` column name implies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JoinTableEdges {
+    pub table: String,
+    pub left: ForeignKeyEdge,
+    pub right: ForeignKeyEdge,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForeignKeyEdge {
+    pub column: String,
+    pub parent_table: String,
+}
+
+/// A table is a pure join table when its primary key is exactly two `_id`
+/// columns and it declares no other columns beyond those two.
+pub fn is_join_table(table: &DieselTable) -> bool {
+    table.primary_key.len() == 2
+        && table.primary_key.iter().all(|pk| pk.ends_with("_id"))
+        && table.columns.len() == table.primary_key.len()
+        && table
+            .columns
+            .iter()
+            .all(|c| table.primary_key.contains(&c.name))
+}
+
+/// Resolve a join table's two `joinable!` edges, guessing the parent table
+/// name by pluralizing the `_id` column's stem (`address_id` -> `addresses`).
+pub fn discover_join_table_edges(table: &DieselTable) -> Option<JoinTableEdges> {
+    if !is_join_table(table) {
+        return None;
+    }
+    let left_col = &table.primary_key[0];
+    let right_col = &table.primary_key[1];
+    Some(JoinTableEdges {
+        table: table.name.clone(),
+        left: ForeignKeyEdge {
+            column: left_col.clone(),
+            parent_table: pluralize_stem(left_col),
+        },
+        right: ForeignKeyEdge {
+            column: right_col.clone(),
+            parent_table: pluralize_stem(right_col),
+        },
+    })
+}
+
+/// `table_name (col_a, col_b) { ... }` with both primary-key columns in the
+/// key tuple, as Diesel expects for a composite-key table.
+pub fn render_composite_table_header(table: &DieselTable) -> String {
+    format!("{} ({}) {{", table.name, table.primary_key.join(", "))
+}
+
+/// The two `joinable!(join_table -> parent (fk))` edges a join table
+/// implies, one per foreign key.
+pub fn render_join_table_edges(edges: &JoinTableEdges) -> String {
+    format!(
+        "joinable!({} -> {} ({}));\njoinable!({} -> {} ({}));\n",
+        edges.table, edges.left.parent_table, edges.left.column,
+        edges.table, edges.right.parent_table, edges.right.column,
+    )
+}
+
+pub fn pluralize_stem(fk_column: &str) -> String {
+    let stem = fk_column.strip_suffix("_id").unwrap_or(fk_column);
+    super::inflect::pluralize(stem)
+}
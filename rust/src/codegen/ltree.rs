@@ -0,0 +1,181 @@
+//! Materialized-path hierarchy support via Postgres `ltree` (the approach
+//! Lemmy's schema uses with `diesel_ltree`). Gives a table a `path: Ltree`
+//! column plus subtree/ancestor/children query helpers, and the migration
+//! bits (`CREATE EXTENSION ltree`, GiST index) needed to keep them indexed.
+
+/// Whether a column's SQL type (as captured off a `table!` block) is the
+/// Postgres `ltree` materialized-path type.
+pub fn is_ltree_column(sql_type: &str) -> bool {
+    sql_type == "Ltree"
+}
+
+/// The custom `#[diesel(postgres_type(name = "ltree"))]` SQL type marker,
+/// mirroring Lemmy's schema rather than depending on `diesel_ltree`'s own
+/// type so the generated code is self-contained.
+pub fn render_ltree_sql_type() -> &'static str {
+    "#[derive(diesel::sql_types::SqlType)]\n#[diesel(postgres_type(name = \"ltree\"))]\npub struct Ltree;\n"
+}
+
+/// `<@`/`@>`/`~` as Diesel expression methods via `infix_operator!`, so
+/// callers write `entity01s::path.contained_by(x)` instead of free
+/// functions. `~` matches the column against an `lquery` pattern.
+pub fn render_ltree_operators() -> &'static str {
+    "diesel::infix_operator!(ContainedBy, \" <@ \", backend: diesel::pg::Pg);\n\
+     diesel::infix_operator!(Contains, \" @> \", backend: diesel::pg::Pg);\n\
+     diesel::infix_operator!(MatchesLquery, \" ~ \", backend: diesel::pg::Pg);\n\n\
+     pub trait LtreeExpressionMethods: Expression<SqlType = Ltree> + Sized {\n    \
+         fn contained_by<T: AsExpression<Ltree>>(self, other: T) -> ContainedBy<Self, T::Expression> {\n        \
+             ContainedBy::new(self, other.as_expression())\n    \
+         }\n\n    \
+         fn contains<T: AsExpression<Ltree>>(self, other: T) -> Contains<Self, T::Expression> {\n        \
+             Contains::new(self, other.as_expression())\n    \
+         }\n\n    \
+         fn matches_lquery<T: AsExpression<diesel::sql_types::Text>>(self, lquery: T) -> MatchesLquery<Self, T::Expression> {\n        \
+             MatchesLquery::new(self, lquery.as_expression())\n    \
+         }\n\
+     }\n\n\
+     impl<T: Expression<SqlType = Ltree>> LtreeExpressionMethods for T {}\n"
+}
+
+/// `diesel::sql_function!` declarations for the Postgres `ltree` functions
+/// the hierarchy helpers below call (`nlevel` to bound/compare depth,
+/// `subpath` to strip a path's leading segments when re-parenting). Without
+/// these, `nlevel(...)`/`subpath(...)` are out-of-scope free functions and
+/// every generated hierarchy query fails to compile.
+pub fn render_ltree_sql_functions() -> &'static str {
+    "diesel::sql_function!(fn nlevel(path: Ltree) -> Integer);\n\
+     diesel::sql_function!(fn subpath(path: Ltree, offset: Integer) -> Ltree);\n"
+}
+
+/// A table whose FK points back at itself (e.g. `entity07_id` on
+/// `entity07s`, or `parent_id` on `categories`) is a parent chain begging
+/// for a materialized path instead of recursive self-joins. Returns the
+/// self-referential FK column name, if any.
+pub fn parent_chain_column(table: &crate::DieselTable) -> Option<String> {
+    table
+        .columns
+        .iter()
+        .find(|c| {
+            c.name == "parent_id"
+                || (c.name.ends_with("_id") && super::join_tables::pluralize_stem(&c.name) == table.name)
+        })
+        .map(|c| c.name.clone())
+}
+
+/// The migration that adds a `path: Ltree` column to a table already
+/// identified by [`parent_chain_column`] as a self-referential hierarchy,
+/// so its recursive parent chain can be queried via containment instead of
+/// repeated self-joins.
+pub fn render_materialize_path_migration(table: &str) -> Vec<String> {
+    render_ltree_migration(table)
+}
+
+/// Whether `table` sits at least two FK hops deep in the chain described by
+/// `parent_of` (child table -> its immediate parent table), e.g.
+/// `entity47s -> entity06s -> entity05s`. A single hop is an ordinary
+/// `belongs_to`; two or more is the case a materialized path helps with.
+pub fn is_multi_hop_hierarchy(table: &str, parent_of: &std::collections::HashMap<String, String>) -> bool {
+    parent_of.get(table).is_some_and(|parent| parent_of.contains_key(parent))
+}
+
+/// The shared `Hierarchical` trait for any model carrying a `path: Ltree`
+/// column maintained across a multi-hop FK chain: `ancestors`/`descendants`/
+/// `subtree` query builders over the `<@`/`@>`/`nlevel` ltree operators
+/// (`subpath` is used by [`render_reparent_statement`], not by these).
+pub fn render_hierarchical_trait() -> &'static str {
+    "pub trait Hierarchical {\n    \
+         fn ancestors(conn: &mut PgConnection, id: i64) -> QueryResult<Vec<Self>>\n    \
+         where\n        \
+             Self: Sized;\n    \
+         fn descendants(conn: &mut PgConnection, id: i64, depth: Option<u32>) -> QueryResult<Vec<Self>>\n    \
+         where\n        \
+             Self: Sized;\n    \
+         fn subtree(conn: &mut PgConnection, id: i64) -> QueryResult<Vec<Self>>\n    \
+         where\n        \
+             Self: Sized;\n\
+     }\n"
+}
+
+/// `impl Hierarchical for {name}`: `descendants` optionally bounds
+/// `nlevel(path) - nlevel(own_path) <= depth`, the rest are straight
+/// containment queries against the row's own path.
+pub fn render_hierarchical_impl(struct_name: &str, table: &str) -> String {
+    format!(
+        "impl Hierarchical for {name} {{\n    \
+             fn ancestors(conn: &mut PgConnection, id: i64) -> QueryResult<Vec<{name}>> {{\n        \
+                 let own_path = {table}::table.find(id).select({table}::path).first::<Ltree>(conn)?;\n        \
+                 {table}::table.filter({table}::path.contains(&own_path)).load(conn)\n    \
+             }}\n\n    \
+             fn descendants(conn: &mut PgConnection, id: i64, depth: Option<u32>) -> QueryResult<Vec<{name}>> {{\n        \
+                 let own_path = {table}::table.find(id).select({table}::path).first::<Ltree>(conn)?;\n        \
+                 let query = {table}::table.filter({table}::path.contained_by(&own_path));\n        \
+                 match depth {{\n            \
+                     Some(max_depth) => query\n                \
+                         .filter(nlevel({table}::path).le(nlevel(own_path) + max_depth as i32))\n                \
+                         .load(conn),\n            \
+                     None => query.load(conn),\n        \
+                 }}\n    \
+             }}\n\n    \
+             fn subtree(conn: &mut PgConnection, id: i64) -> QueryResult<Vec<{name}>> {{\n        \
+                 Self::descendants(conn, id, None)\n    \
+             }}\n\
+         }}\n",
+        name = struct_name,
+        table = table,
+    )
+}
+
+/// Re-parenting statement: rewrite every row's path under `old_prefix` to
+/// hang off `new_prefix` instead, in one `UPDATE`, using `subpath` to strip
+/// the old ancestor segment and `||` to splice the new one on.
+pub fn render_reparent_statement(table: &str) -> String {
+    format!(
+        "UPDATE {table} SET path = new_prefix || subpath(path, nlevel(old_prefix))\n\
+         WHERE path <@ old_prefix;\n",
+    )
+}
+
+/// Query helper methods generated for a table that gained a `path: Ltree`
+/// column, using the `<@`/`@>` containment operators.
+pub fn render_hierarchy_helpers(struct_name: &str, table: &str) -> String {
+    format!(
+        "impl {name} {{\n    \
+             /// All descendants of `ancestor_path` (`path <@ ancestor_path`).\n    \
+             pub fn subtree(conn: &mut PgConnection, ancestor_path: &Ltree) -> QueryResult<Vec<{name}>> {{\n        \
+                 {table}::table\n            \
+                     .filter({table}::path.contained_by(ancestor_path))\n            \
+                     .load(conn)\n    \
+             }}\n\n    \
+             /// All ancestors of `descendant_path` (`path @> descendant_path`).\n    \
+             pub fn ancestors(conn: &mut PgConnection, descendant_path: &Ltree) -> QueryResult<Vec<{name}>> {{\n        \
+                 {table}::table\n            \
+                     .filter({table}::path.contains(descendant_path))\n            \
+                     .load(conn)\n    \
+             }}\n\n    \
+             /// Direct children: one level below `parent_path`.\n    \
+             pub fn children(conn: &mut PgConnection, parent_path: &Ltree) -> QueryResult<Vec<{name}>> {{\n        \
+                 {table}::table\n            \
+                     .filter({table}::path.contained_by(parent_path))\n            \
+                     .filter(nlevel({table}::path).eq(nlevel(parent_path) + 1))\n            \
+                     .load(conn)\n    \
+             }}\n\n    \
+             /// Compute a new child's path from its parent's path and its own id.\n    \
+             pub fn child_path(parent_path: &Ltree, new_id: i64) -> Ltree {{\n        \
+                 Ltree(format!(\"{{}}.{{}}\", parent_path.0, new_id))\n    \
+             }}\n\
+         }}\n",
+        name = struct_name,
+        table = table,
+    )
+}
+
+/// Migration statements for the `path` column: the extension, an
+/// `ALTER TABLE ... ADD COLUMN path ltree`, and a GiST index so
+/// ancestor/descendant lookups stay fast.
+pub fn render_ltree_migration(table: &str) -> Vec<String> {
+    vec![
+        "CREATE EXTENSION IF NOT EXISTS ltree;".to_string(),
+        format!("ALTER TABLE {} ADD COLUMN path ltree;", table),
+        format!("CREATE INDEX {}_path_gist_idx ON {} USING GIST (path);", table, table),
+    ]
+}
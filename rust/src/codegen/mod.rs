@@ -0,0 +1,26 @@
+//! Code generation helpers that turn the metadata `extract_struct_info` et al.
+//! collect into ready-to-paste Diesel plumbing. Each submodule owns one
+//! generated artifact (newtype ids, associations, enum SQL mapping, ...) so
+//! the generator can grow new `--emit` targets without the modules stepping
+//! on each other.
+
+pub mod allow_tables;
+pub mod associations;
+pub mod audit;
+pub mod components;
+pub mod dto;
+pub mod entity;
+pub mod dummy;
+pub mod entity_macro;
+pub mod geometry;
+pub mod ids;
+pub mod inflect;
+pub mod join_tables;
+pub mod ltree;
+pub mod models;
+pub mod ranges;
+pub mod seed;
+pub mod soft_delete;
+pub mod sql_types_mod;
+pub mod status_enum;
+pub mod type_map;
@@ -0,0 +1,185 @@
+//! Row struct generation to go with the generated `table!` schema, mirroring
+//! fatcat's `database_models.rs`: one `Queryable`/`Identifiable`/
+//! `Associations`/`AsChangeset` struct per table plus a matching `New*`
+//! insert struct with the auto-generated `id` and the `deleted_at` columns
+//! dropped.
+
+use crate::DieselTable;
+use crate::codegen::associations::BelongsTo;
+use crate::codegen::ids::{self, IdNewType};
+
+/// `Serialize`/`Deserialize`/`Debug`/`Clone` apply unconditionally, so a
+/// serde-only API-types crate or a `wasm32` frontend can depend on the
+/// model without pulling in Diesel; `Queryable`/`Identifiable`/
+/// `Associations`/`AsChangeset` and the `#[diesel(...)]` attributes only
+/// apply under the `full` feature, the split Lemmy's `#[cfg_attr(feature =
+/// "full", derive(...))]` models use. Every column is mapped to its Rust
+/// type (retyping `id`/`*_id` columns to their generated newtype where one
+/// exists) and one `#[diesel(belongs_to(Parent, foreign_key = fk))]` per
+/// relation so `belongs_to`/`grouped_by` eager-loading works out of the box
+/// once `full` is on.
+pub fn render_row_struct(table: &DieselTable, relations: &[BelongsTo], known_ids: &[IdNewType]) -> String {
+    let struct_name = table_to_struct_name(&table.name);
+    let mut out = String::from(
+        "#[derive(Debug, Clone, Serialize, Deserialize)]\n\
+         #[cfg_attr(feature = \"full\", derive(Queryable, Identifiable, Associations, AsChangeset))]\n",
+    );
+    for relation in relations {
+        out.push_str(&format!(
+            "#[cfg_attr(feature = \"full\", diesel(belongs_to({}, foreign_key = {})))]\n",
+            relation.parent, relation.foreign_key
+        ));
+    }
+    out.push_str(&format!("#[cfg_attr(feature = \"full\", diesel(table_name = {}))]\n", table.name));
+    out.push_str(&format!("pub struct {struct_name} {{\n"));
+    for column in &table.columns {
+        let rust_type = column_rust_type(&struct_name, column, known_ids);
+        out.push_str(&format!("    pub {}: {},\n", column.name, rust_type));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// The matching `New*` insert struct, identical but for dropping the
+/// auto-generated `id` primary key column and the soft-delete `deleted_at`
+/// column. `Insertable` and its
+/// `#[diesel(table_name = ...)]` are `full`-gated the same way as
+/// [`render_row_struct`].
+pub fn render_new_row_struct(table: &DieselTable, known_ids: &[IdNewType]) -> String {
+    let struct_name = table_to_struct_name(&table.name);
+    let mut out = format!(
+        "#[derive(Debug, Clone, Serialize, Deserialize)]\n\
+         #[cfg_attr(feature = \"full\", derive(Insertable))]\n\
+         #[cfg_attr(feature = \"full\", diesel(table_name = {}))]\n\
+         pub struct New{struct_name} {{\n",
+        table.name
+    );
+    for column in &table.columns {
+        if matches!(column.name.as_str(), "id" | "deleted_at") {
+            continue;
+        }
+        let rust_type = column_rust_type(&struct_name, column, known_ids);
+        out.push_str(&format!("    pub {}: {},\n", column.name, rust_type));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// The matching `EntityNNChangeset` struct: `#[derive(AsChangeset)]` (also
+/// `full`-gated, see [`render_row_struct`]) with every updatable column
+/// wrapped in `Option<T>` so `.set(&changeset)` only writes the fields
+/// callers actually touched. Drops `id` (never updated) and, on audited
+/// entities, `created_at`/`created_by` (set once on insert).
+pub fn render_changeset_struct(table: &DieselTable, known_ids: &[IdNewType]) -> String {
+    let struct_name = table_to_struct_name(&table.name);
+    let mut out = format!(
+        "#[derive(Debug, Clone, Default, Serialize, Deserialize)]\n\
+         #[cfg_attr(feature = \"full\", derive(AsChangeset))]\n\
+         #[cfg_attr(feature = \"full\", diesel(table_name = {}))]\n\
+         pub struct {struct_name}Changeset {{\n",
+        table.name,
+    );
+    for column in &table.columns {
+        if matches!(column.name.as_str(), "id" | "created_at" | "created_by") {
+            continue;
+        }
+        let rust_type = column_rust_type(&struct_name, column, known_ids);
+        let rust_type = if rust_type.starts_with("Option<") { rust_type } else { format!("Option<{rust_type}>") };
+        out.push_str(&format!("    pub {}: {},\n", column.name, rust_type));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Resolve a column to the id newtype it should use (`id` -> the table's own
+/// `{Struct}Id`, `<parent>_id` -> the parent's `{Parent}Id`), falling back to
+/// the plain SQL-mapped type when no matching newtype was generated.
+fn column_rust_type(struct_name: &str, column: &crate::DieselColumn, known_ids: &[IdNewType]) -> String {
+    let own_id = known_ids.iter().find(|id| id.name == format!("{struct_name}Id"));
+    let retyped = if column.name == "id" {
+        own_id.map(|id| id.name.clone())
+    } else {
+        ids::resolve_fk_id_type(&column.name, known_ids)
+    };
+    match retyped {
+        Some(id_type) if column.is_nullable => format!("Option<{id_type}>"),
+        Some(id_type) => id_type,
+        None => sql_type_to_rust(&column.sql_type, column.is_nullable),
+    }
+}
+
+/// Map a Diesel SQL type (as captured off a `table!` column, already
+/// stripped of its `Nullable<...>` wrapper) to the Rust type it loads into,
+/// wrapping in `Option` when the column is nullable.
+pub fn sql_type_to_rust(sql_type: &str, is_nullable: bool) -> String {
+    let base = if let Some(inner) = sql_type.strip_prefix("Array<").and_then(|s| s.strip_suffix('>')) {
+        format!("Vec<{}>", sql_type_to_rust(inner, false))
+    } else {
+        match sql_type {
+            "Int8" => "i64".to_string(),
+            "Int4" => "i32".to_string(),
+            "Int2" => "i16".to_string(),
+            "Text" | "Varchar" => "String".to_string(),
+            "Bool" => "bool".to_string(),
+            "Uuid" => "uuid::Uuid".to_string(),
+            "Jsonb" | "Json" => "serde_json::Value".to_string(),
+            "Timestamp" => "chrono::NaiveDateTime".to_string(),
+            "Timestamptz" => "chrono::DateTime<chrono::Utc>".to_string(),
+            "Float4" => "f32".to_string(),
+            "Float8" => "f64".to_string(),
+            "Point" => "diesel_geometry::data_types::GeoPoint".to_string(),
+            "Ltree" => "String".to_string(),
+            "Int4range" => "std::ops::Range<i32>".to_string(),
+            "Int8range" => "std::ops::Range<i64>".to_string(),
+            "Daterange" => "std::ops::Range<chrono::NaiveDate>".to_string(),
+            "Tsrange" => "std::ops::Range<chrono::NaiveDateTime>".to_string(),
+            "Tstzrange" => "std::ops::Range<chrono::DateTime<chrono::Utc>>".to_string(),
+            "Numrange" => "std::ops::Range<bigdecimal::BigDecimal>".to_string(),
+            other => other.to_string(),
+        }
+    };
+    if is_nullable {
+        format!("Option<{base}>")
+    } else {
+        base
+    }
+}
+
+/// Find every `<parent>_id` column on `table` and resolve it to a
+/// `belongs_to` relationship, the same `_id`-suffix convention
+/// `codegen::associations::discover_belongs_to` uses for structs.
+pub fn discover_table_belongs_to(table: &DieselTable) -> Vec<BelongsTo> {
+    table
+        .columns
+        .iter()
+        .filter_map(|c| {
+            let parent = c.name.strip_suffix("_id")?;
+            Some(BelongsTo {
+                parent: table_to_struct_name(&super::inflect::pluralize(parent)),
+                foreign_key: c.name.clone(),
+            })
+        })
+        .collect()
+}
+
+/// The `full`-gated imports these model structs need: `diesel::prelude::*`
+/// for the derives/traits, and the generated schema for the `table_name`
+/// idents they reference. Plain `default-features = false` consumers (a
+/// serde-only API-types crate, a `wasm32` frontend) never pull either in.
+pub fn render_full_feature_imports() -> &'static str {
+    "#[cfg(feature = \"full\")]\nuse diesel::prelude::*;\n#[cfg(feature = \"full\")]\nuse crate::schema::*;\n"
+}
+
+pub fn table_to_struct_name(table_name: &str) -> String {
+    let singular = super::inflect::singularize(table_name);
+    singular
+        .split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,55 @@
+//! Postgres range column support (`int4range`, `tsrange`, `daterange`, ...).
+//! Diesel's built-in `sql_types` already has a `Range<T>` marker for each of
+//! these, but no containment/overlap expression methods ship for it, so a
+//! generated range column can't be filtered on without hand-writing
+//! `infix_operator!` plumbing every time.
+
+/// Recognized Postgres range SQL types and the element type each wraps, so
+/// the containment-by-element helper type-checks against the right type.
+const RANGE_SQL_TYPES: &[(&str, &str)] = &[
+    ("Int4range", "Int4"),
+    ("Int8range", "Int8"),
+    ("Numrange", "Numeric"),
+    ("Tsrange", "Timestamp"),
+    ("Tstzrange", "Timestamptz"),
+    ("Daterange", "Date"),
+];
+
+/// Whether a column's SQL type is one of the recognized Postgres range
+/// types.
+pub fn is_range_sql_type(sql_type: &str) -> bool {
+    RANGE_SQL_TYPES.iter().any(|(range, _)| *range == sql_type)
+}
+
+/// The element type a range type wraps (`Int4range` -> `Int4`), needed so
+/// the element-containment helper's argument type-checks.
+pub fn element_type_of(range_sql_type: &str) -> Option<&'static str> {
+    RANGE_SQL_TYPES
+        .iter()
+        .find(|(range, _)| *range == range_sql_type)
+        .map(|(_, element)| *element)
+}
+
+/// `@>`/`&&` as Diesel expression methods over a specific range SQL type,
+/// plus element containment (`range @> element`), mirroring the approach
+/// `diesel_ltree` uses for its own containment operators.
+pub fn render_range_operators(range_sql_type: &str) -> Option<String> {
+    let element_sql_type = element_type_of(range_sql_type)?;
+    Some(format!(
+        "diesel::infix_operator!(Contains{range_sql_type}, \" @> \", backend: diesel::pg::Pg);\n\
+         diesel::infix_operator!(ContainsElement{range_sql_type}, \" @> \", backend: diesel::pg::Pg);\n\
+         diesel::infix_operator!(Overlaps{range_sql_type}, \" && \", backend: diesel::pg::Pg);\n\n\
+         pub trait {range_sql_type}RangeExpressionMethods: Expression<SqlType = {range_sql_type}> + Sized {{\n    \
+             fn contains<T: AsExpression<{range_sql_type}>>(self, other: T) -> Contains{range_sql_type}<Self, T::Expression> {{\n        \
+                 Contains{range_sql_type}::new(self, other.as_expression())\n    \
+             }}\n\n    \
+             fn contains_element<T: AsExpression<{element_sql_type}>>(self, element: T) -> ContainsElement{range_sql_type}<Self, T::Expression> {{\n        \
+                 ContainsElement{range_sql_type}::new(self, element.as_expression())\n    \
+             }}\n\n    \
+             fn overlaps<T: AsExpression<{range_sql_type}>>(self, other: T) -> Overlaps{range_sql_type}<Self, T::Expression> {{\n        \
+                 Overlaps{range_sql_type}::new(self, other.as_expression())\n    \
+             }}\n\
+         }}\n\n\
+         impl<T: Expression<SqlType = {range_sql_type}>> {range_sql_type}RangeExpressionMethods for T {{}}\n",
+    ))
+}
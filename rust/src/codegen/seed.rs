@@ -0,0 +1,82 @@
+//! `#[cfg(feature = "dummy")]` batch seeding: a `Seedable` trait implemented
+//! per `New*` insert struct (mirroring the `SoftDelete`/`Hierarchical`
+//! one-trait-plus-generated-impl shape), and a topological table order so
+//! `seed_all` inserts FK parents before the children that reference them.
+
+use std::collections::{HashMap, HashSet};
+
+/// The shared `Seedable` trait: generate and insert `n` fake rows, handing
+/// back the inserted `Row`s.
+pub fn render_seedable_trait() -> &'static str {
+    "pub trait Seedable: Sized {\n    \
+         type Row;\n\n    \
+         fn generate_batch(n: usize, conn: &mut PgConnection) -> QueryResult<Vec<Self::Row>>;\n\
+     }\n"
+}
+
+/// `impl Seedable for New{struct}`: generates `n` fake instances via
+/// `fake::Dummy` and inserts them in one statement.
+pub fn render_seedable_impl(struct_name: &str, table: &str) -> String {
+    format!(
+        "#[cfg(feature = \"dummy\")]\n\
+         impl Seedable for New{name} {{\n    \
+             type Row = {name};\n\n    \
+             fn generate_batch(n: usize, conn: &mut PgConnection) -> QueryResult<Vec<{name}>> {{\n        \
+                 let rows: Vec<New{name}> = (0..n).map(|_| fake::Faker.fake()).collect();\n        \
+                 diesel::insert_into({table}::table).values(rows).get_results(conn)\n    \
+             }}\n\
+         }}\n",
+        name = struct_name,
+        table = table,
+    )
+}
+
+/// Order `tables` so every FK parent (per `edges`, child -> parent) comes
+/// before its children, the same dependency a topological sort over
+/// `belongs_to` gives for migrations. Self-referencing edges are ignored.
+/// Any table left over once no more progress can be made (a cycle, or a
+/// parent that isn't in `tables`) is appended as-is rather than dropped.
+pub fn topological_table_order(tables: &[String], edges: &[(String, String)]) -> Vec<String> {
+    let mut parents_of: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (child, parent) in edges {
+        if child != parent {
+            parents_of.entry(child.as_str()).or_default().push(parent.as_str());
+        }
+    }
+
+    let mut ordered = Vec::new();
+    let mut seeded: HashSet<&str> = HashSet::new();
+    let mut remaining: Vec<&str> = tables.iter().map(|t| t.as_str()).collect();
+
+    while !remaining.is_empty() {
+        let ready: Vec<&str> = remaining
+            .iter()
+            .copied()
+            .filter(|t| parents_of.get(t).is_none_or(|parents| parents.iter().all(|p| seeded.contains(p))))
+            .collect();
+        if ready.is_empty() {
+            ordered.extend(remaining.iter().map(|t| t.to_string()));
+            break;
+        }
+        for table in &ready {
+            seeded.insert(table);
+            ordered.push(table.to_string());
+        }
+        remaining.retain(|t| !ready.contains(t));
+    }
+    ordered
+}
+
+/// `seed_all`: call `generate_batch` on every ordered struct's `New*` type
+/// in FK-safe order, so a child table is never seeded before the parent row
+/// its FK points at exists.
+pub fn render_seed_all(ordered_struct_names: &[String]) -> String {
+    let mut out = String::from(
+        "#[cfg(feature = \"dummy\")]\npub fn seed_all(conn: &mut PgConnection, n: usize) -> QueryResult<()> {\n",
+    );
+    for struct_name in ordered_struct_names {
+        out.push_str(&format!("    New{struct_name}::generate_batch(n, conn)?;\n"));
+    }
+    out.push_str("    Ok(())\n}\n");
+    out
+}
@@ -0,0 +1,94 @@
+//! Soft-delete plumbing for any model carrying a `deleted_at:
+//! Option<NaiveDateTime>` column: a `SoftDelete` impl with `soft_delete`/
+//! `restore` helpers and `active_only()`/`with_trashed()`/`only_trashed()`
+//! query scopes, plus an `EntityState` lifecycle (`Active`,
+//! `Redirect(target_id)`, `Deleted`) in the style of fatcat's records. The
+//! generated `state()` derives only `Active`/`Deleted` from `deleted_at`; it
+//! never returns `Redirect`.
+
+use crate::RustStruct;
+
+/// A model qualifies for soft-delete support if it has a nullable
+/// `deleted_at` column.
+pub fn supports_soft_delete(s: &RustStruct) -> bool {
+    s.fields
+        .iter()
+        .any(|f| f.name == "deleted_at" && f.is_optional)
+}
+
+/// The shared `SoftDelete` trait and `EntityState` enum, emitted once and
+/// implemented per qualifying model.
+pub fn render_soft_delete_trait() -> &'static str {
+    "pub trait SoftDelete {\n    \
+         type Id;\n\n    \
+         fn deleted_at(&self) -> Option<NaiveDateTime>;\n    \
+         fn soft_delete(conn: &mut PgConnection, id: Self::Id) -> QueryResult<usize>\n    \
+         where\n        \
+             Self: Sized;\n    \
+         fn restore(conn: &mut PgConnection, id: Self::Id) -> QueryResult<usize>\n    \
+         where\n        \
+             Self: Sized;\n    \
+         fn state(&self) -> EntityState;\n\
+     }\n\n\
+     /// Lifecycle derived from `deleted_at` rather than stored directly.\n\
+     /// The generated `state()` impls return `Active` or `Deleted` only.\n\
+     #[derive(Debug, Clone, Copy, PartialEq, Eq)]\n\
+     pub enum EntityState<Id = i64> {\n    \
+         Active,\n    \
+         Redirect(Id),\n    \
+         Deleted,\n\
+     }\n"
+}
+
+/// Render the `impl SoftDelete for {name}` block: `soft_delete`/`restore`
+/// stamp or clear `deleted_at` and bump `updated_at` in the same statement,
+/// and `active_only()`/`with_trashed()`/`only_trashed()` are query-scope
+/// helpers over `deleted_at IS NULL`. `active_only` supersedes the
+/// `active_query` name chunk4-5 introduced: once `only_trashed` joined
+/// `with_trashed`, `active_query` was the odd one out against the
+/// `{state}_{trashed,only}` pattern the other two scopes share, so it's
+/// renamed again here rather than left inconsistent.
+pub fn render_soft_delete_impl(s: &RustStruct, table: &str) -> String {
+    let name = &s.name;
+    format!(
+        "impl SoftDelete for {name} {{\n    \
+             type Id = i64;\n\n    \
+             fn deleted_at(&self) -> Option<NaiveDateTime> {{\n        \
+                 self.deleted_at\n    \
+             }}\n\n    \
+             fn soft_delete(conn: &mut PgConnection, id: Self::Id) -> QueryResult<usize> {{\n        \
+                 let now = Utc::now().naive_utc();\n        \
+                 diesel::update({table}::table.find(id))\n            \
+                     .set(({table}::deleted_at.eq(Some(now)), {table}::updated_at.eq(now)))\n            \
+                     .execute(conn)\n    \
+             }}\n\n    \
+             fn restore(conn: &mut PgConnection, id: Self::Id) -> QueryResult<usize> {{\n        \
+                 diesel::update({table}::table.find(id))\n            \
+                     .set(({table}::deleted_at.eq(None::<NaiveDateTime>), {table}::updated_at.eq(Utc::now().naive_utc())))\n            \
+                     .execute(conn)\n    \
+             }}\n\n    \
+             fn state(&self) -> EntityState {{\n        \
+                 match self.deleted_at {{\n            \
+                     Some(_) => EntityState::Deleted,\n            \
+                     None => EntityState::Active,\n        \
+                 }}\n    \
+             }}\n\
+         }}\n\n\
+         impl {name} {{\n    \
+             /// Default query scope: only rows that haven't been soft-deleted.\n    \
+             pub fn active_only() -> {table}::BoxedQuery<'static, diesel::pg::Pg> {{\n        \
+                 {table}::table.filter({table}::deleted_at.is_null()).into_boxed()\n    \
+             }}\n\n    \
+             /// Escape hatch that includes tombstoned rows.\n    \
+             pub fn with_trashed() -> {table}::BoxedQuery<'static, diesel::pg::Pg> {{\n        \
+                 {table}::table.into_boxed()\n    \
+             }}\n\n    \
+             /// Only the tombstoned rows.\n    \
+             pub fn only_trashed() -> {table}::BoxedQuery<'static, diesel::pg::Pg> {{\n        \
+                 {table}::table.filter({table}::deleted_at.is_not_null()).into_boxed()\n    \
+             }}\n\
+         }}\n",
+        name = name,
+        table = table,
+    )
+}
@@ -0,0 +1,52 @@
+//! `pub mod sql_types { ... }` generation for Postgres `ENUM` columns, so a
+//! `table!` block can reference `status -> sql_types::Entity01StatusType`
+//! instead of the lossy `Text` every constrained column collapses to today.
+//! Mirrors how Lemmy-style generated Diesel schemas surface enums.
+
+/// One `#[derive(diesel::sql_types::SqlType)] #[diesel(postgres_type(name =
+/// "..."))]` marker struct, keyed by the Postgres type name it backs.
+pub fn render_sql_type_marker(enum_name: &str, pg_type_name: &str) -> String {
+    format!(
+        "#[derive(diesel::sql_types::SqlType)]\n#[diesel(postgres_type(name = \"{pg_type_name}\"))]\npub struct {enum_name}Type;\n",
+    )
+}
+
+/// The `pub mod sql_types { ... }` block gathering every marker, placed
+/// ahead of the `table!` declarations that reference it.
+pub fn render_sql_types_module(enums: &[(String, String)]) -> String {
+    let mut out = String::from("pub mod sql_types {\n");
+    for (enum_name, pg_type_name) in enums {
+        for line in render_sql_type_marker(enum_name, pg_type_name).lines() {
+            out.push_str("    ");
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Wrap already-rendered marker(s) (e.g. the `Ltree` struct from
+/// `codegen::ltree`) in the shared `pub mod sql_types { ... }` block, for
+/// markers that don't fit the enum-keyed [`render_sql_types_module`] shape.
+pub fn wrap_in_sql_types_module(markers: &[String]) -> String {
+    let mut out = String::from("pub mod sql_types {\n");
+    for marker in markers {
+        for line in marker.lines() {
+            out.push_str("    ");
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// A `table!` column line referencing the generated SQL type instead of
+/// `Text`, plus the `use` it needs.
+pub fn render_enum_column(column: &str, enum_name: &str) -> (String, String) {
+    (
+        format!("use self::sql_types::{enum_name}Type;"),
+        format!("{column} -> {enum_name}Type,"),
+    )
+}
@@ -0,0 +1,137 @@
+//! Diesel plumbing for `*Status`-style unit enums: a `SqlType` marker, the
+//! `ToSql`/`FromSql` impls that round-trip each variant through its
+//! lowercase string label, and the matching `CREATE TYPE ... AS ENUM`
+//! migration line so the Rust type and the Postgres type stay in sync.
+//! Follows the bvplan models, which back their Diesel enums with explicit
+//! `ToSql`/`FromSql` string conversions.
+
+/// Render the SQL-type marker, `ToSql`, and `FromSql` impls for a status
+/// enum whose unit variants map 1:1 to `variants` (in declared order). Use
+/// this standalone; callers that already emit the marker elsewhere (e.g.
+/// `pg-enums`, via `codegen::sql_types_mod`'s `sql_types` module) should
+/// call [`render_status_to_from_sql`] instead so the marker isn't declared
+/// twice.
+pub fn render_status_sql_impls(enum_name: &str, sql_type_name: &str, variants: &[&str]) -> String {
+    let mut out = format!(
+        "#[derive(SqlType)]\n#[diesel(postgres_type(name = \"{sql_type_name}\"))]\npub struct {enum_name}Type;\n\n",
+    );
+    out.push_str(&render_status_to_from_sql(enum_name, variants));
+    out
+}
+
+/// The `ToSql`/`FromSql` impls alone, with no `{enum_name}Type` marker
+/// declaration, for callers that already have one in scope.
+pub fn render_status_to_from_sql(enum_name: &str, variants: &[&str]) -> String {
+    let mut out = String::new();
+
+    out.push_str(&format!("impl ToSql<{enum_name}Type, Pg> for {enum_name} {{\n"));
+    out.push_str("    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {\n");
+    out.push_str("        let label = match self {\n");
+    for variant in variants {
+        out.push_str(&format!(
+            "            {enum_name}::{variant} => \"{label}\",\n",
+            variant = variant,
+            label = to_snake_label(variant),
+        ));
+    }
+    out.push_str("        };\n        out.write_all(label.as_bytes())?;\n        Ok(IsNull::No)\n    }\n}\n\n");
+
+    out.push_str(&format!("impl FromSql<{enum_name}Type, Pg> for {enum_name} {{\n"));
+    out.push_str("    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {\n");
+    out.push_str("        match std::str::from_utf8(bytes.as_bytes())? {\n");
+    for variant in variants {
+        out.push_str(&format!(
+            "            \"{label}\" => Ok({enum_name}::{variant}),\n",
+            label = to_snake_label(variant),
+            variant = variant,
+        ));
+    }
+    out.push_str(&format!(
+        "            other => Err(format!(\"unrecognized {enum_name} value: {{}}\", other).into()),\n"
+    ));
+    out.push_str("        }\n    }\n}\n");
+
+    out
+}
+
+/// Render the Diesel plumbing for a status enum backed by a plain `TEXT`
+/// column rather than a Postgres native enum type: the `AsExpression`/
+/// `FromSqlRow` header pinning it to `sql_types::Text`, `ToSql`/`FromSql`
+/// against `Text`, and `Display`/`FromStr` using the same lowercase label
+/// mapping so `status.to_string()` and `"draft".parse()` round-trip it too.
+pub fn render_text_backed_status_impls(enum_name: &str, variants: &[&str]) -> String {
+    let mut out = String::new();
+
+    out.push_str("#[derive(AsExpression, FromSqlRow)]\n#[diesel(sql_type = Text)]\n");
+    out.push_str(&format!("pub enum {enum_name} {{ /* variants as declared above */ }}\n\n"));
+
+    out.push_str(&format!("impl {enum_name} {{\n"));
+    out.push_str("    pub fn shortname(&self) -> &'static str {\n        match self {\n");
+    for variant in variants {
+        out.push_str(&format!(
+            "            {enum_name}::{variant} => \"{label}\",\n",
+            label = to_snake_label(variant),
+        ));
+    }
+    out.push_str("        }\n    }\n}\n\n");
+
+    out.push_str(&format!("impl ToSql<Text, Pg> for {enum_name} {{\n"));
+    out.push_str("    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {\n");
+    out.push_str("        out.write_all(self.shortname().as_bytes())?;\n        Ok(IsNull::No)\n    }\n}\n\n");
+
+    out.push_str(&format!("impl FromSql<Text, Pg> for {enum_name} {{\n"));
+    out.push_str("    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {\n");
+    out.push_str(&format!("        {enum_name}::from_str(std::str::from_utf8(bytes.as_bytes())?).map_err(Into::into)\n    }}\n}}\n\n"));
+
+    out.push_str(&format!("impl std::fmt::Display for {enum_name} {{\n"));
+    out.push_str("    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {\n        f.write_str(self.shortname())\n    }\n}\n\n");
+
+    out.push_str(&format!("impl std::str::FromStr for {enum_name} {{\n    type Err = String;\n"));
+    out.push_str("    fn from_str(s: &str) -> Result<Self, Self::Err> {\n        match s {\n");
+    for variant in variants {
+        out.push_str(&format!(
+            "            \"{label}\" => Ok({enum_name}::{variant}),\n",
+            label = to_snake_label(variant),
+        ));
+    }
+    out.push_str(&format!(
+        "            other => Err(format!(\"unrecognized {enum_name} value: {{}}\", other)),\n"
+    ));
+    out.push_str("        }\n    }\n}\n");
+
+    out
+}
+
+/// Render the `CREATE TYPE ... AS ENUM (...)` migration statement matching
+/// the Rust side generated by [`render_status_sql_impls`].
+pub fn render_create_type_migration(sql_type_name: &str, variants: &[&str]) -> String {
+    let labels: Vec<String> = variants.iter().map(|v| format!("'{}'", to_snake_label(v))).collect();
+    format!("CREATE TYPE {} AS ENUM ({});", sql_type_name, labels.join(", "))
+}
+
+/// The `#[derive(DbEnum)]` alternative to the hand-written `ToSql`/`FromSql`
+/// impls above, for users who'd rather pull in `diesel-derive-enum` than
+/// maintain the mapping by hand. Points `ExistingTypePath` at the
+/// `{enum_name}Type` marker `codegen::sql_types_mod::render_sql_types_module`
+/// declares in `schema::sql_types`, the module Diesel's `diesel print-schema`
+/// puts postgres-type markers in.
+pub fn render_db_enum_derive(enum_name: &str) -> String {
+    format!(
+        "#[derive(diesel_derive_enum::DbEnum, Debug, Clone, Copy, PartialEq, Eq)]\n#[ExistingTypePath = \"crate::schema::sql_types::{enum_name}Type\"]\npub enum {enum_name} {{ /* variants as declared above */ }}\n",
+    )
+}
+
+fn to_snake_label(variant: &str) -> String {
+    let mut out = String::new();
+    for (i, ch) in variant.chars().enumerate() {
+        if ch.is_uppercase() {
+            if i != 0 {
+                out.push('_');
+            }
+            out.extend(ch.to_lowercase());
+        } else {
+            out.push(ch);
+        }
+    }
+    out
+}
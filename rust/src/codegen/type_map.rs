@@ -0,0 +1,48 @@
+//! A configurable Rust-type -> Postgres-SQL-type mapping table, so wrapper
+//! types that implement Diesel's `Expression` themselves (e.g.
+//! `arraystring::ArrayString<N>`, whose `SqlType = VarChar`) can be
+//! registered instead of falling back to `"Unknown"`. Loaded from a small
+//! JSON file passed on the CLI via `--type-map <path>`.
+
+use std::collections::HashMap;
+use crate::TypeInfo;
+
+/// User-registered overrides, keyed by the Rust base type name.
+#[derive(Debug, Clone, Default)]
+pub struct TypeMap {
+    overrides: HashMap<String, String>,
+}
+
+impl TypeMap {
+    pub fn from_json(raw: &str) -> Result<Self, serde_json::Error> {
+        let overrides: HashMap<String, String> = serde_json::from_str(raw)?;
+        Ok(TypeMap { overrides })
+    }
+
+    /// Resolve a Rust type to its Postgres column type, preferring a
+    /// user-registered override, then a handful of built-in conventions,
+    /// and finally falling back to `"Unknown"` rather than guessing.
+    pub fn resolve(&self, type_info: &TypeInfo) -> String {
+        if let Some(sql_type) = self.overrides.get(&type_info.base) {
+            return sql_type.clone();
+        }
+
+        match type_info.base.as_str() {
+            "Vec" if type_info.is_collection => type_info
+                .generics
+                .first()
+                .map(|inner| format!("{}[]", self.resolve(inner)))
+                .unwrap_or_else(|| "Unknown[]".to_string()),
+            "String" => "text".to_string(),
+            "i64" => "int8".to_string(),
+            "i32" => "int4".to_string(),
+            "bool" => "bool".to_string(),
+            "Uuid" => "uuid".to_string(),
+            "Value" => "jsonb".to_string(),
+            "NaiveDateTime" => "timestamp".to_string(),
+            "DateTime" => "timestamptz".to_string(),
+            "Ltree" => "ltree".to_string(),
+            _ => "Unknown".to_string(),
+        }
+    }
+}
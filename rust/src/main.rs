@@ -4,12 +4,20 @@ use syn::{ItemStruct, Fields, Field, Type};
 use quote::ToTokens;
 use serde::{Serialize, Deserialize};
 
+mod codegen;
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct RustField {
     pub name: String,
     pub field_type: String,
     pub is_optional: bool,
     pub attributes: Vec<String>,
+    /// `#[diesel(column_name = "...")]` rename, if present.
+    pub column_name: Option<String>,
+    /// `#[diesel(sql_type = ...)]` override, if present.
+    pub sql_type: Option<String>,
+    /// Fully recursive type shape, e.g. `Vec<String>`'s element type.
+    pub type_info: TypeInfo,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -26,6 +34,33 @@ pub struct DieselTable {
     pub columns: Vec<DieselColumn>,
 }
 
+/// A `DieselNewType`-derived single-field tuple struct, e.g.
+/// `pub struct PostId(pub i32);` — a type-safe id wrapper that still
+/// serializes as its inner column type.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RustNewType {
+    pub name: String,
+    pub inner_type: String,
+    pub derives: Vec<String>,
+}
+
+/// One unit variant of a `RustEnum`, e.g. `Draft` in `enum Status { Draft, ... }`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RustEnumVariant {
+    pub ident: String,
+    /// An explicit `= N` discriminant, or a `#[serde(rename = "...")]`
+    /// label when no discriminant is present.
+    pub discriminant: Option<String>,
+}
+
+/// A plain Rust enum used as a column type (e.g. `Entity26Status`).
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RustEnum {
+    pub name: String,
+    pub variants: Vec<RustEnumVariant>,
+    pub attributes: Vec<String>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct DieselColumn {
     pub name: String,
@@ -33,11 +68,21 @@ pub struct DieselColumn {
     pub is_nullable: bool,
 }
 
+/// One `#[diesel(belongs_to(Parent, foreign_key = ...))]` relationship.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BelongsTo {
+    pub parent: String,
+    pub foreign_key: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct DieselDerive {
     pub struct_name: String,
     pub derives: Vec<String>,
     pub associations: Vec<String>,
+    pub table_name: Option<String>,
+    pub belongs_to: Vec<BelongsTo>,
+    pub primary_key: Vec<String>,
 }
 
 fn extract_struct_info(struct_item: &ItemStruct) -> Result<RustStruct, String> {
@@ -78,11 +123,37 @@ fn extract_field_info(field: &Field) -> Result<RustField, String> {
         None => return Err("Unnamed field".to_string()),
     };
 
-    let (field_type, is_optional) = extract_type_info(&field.ty)?;
+    let type_info = extract_type_info(&field.ty)?;
+    let field_type = type_info.base.clone();
+    let is_optional = type_info.is_optional;
 
     let mut attributes = Vec::new();
+    let mut column_name = None;
+    let mut sql_type = None;
     for attr in &field.attrs {
         attributes.push(attr.to_token_stream().to_string());
+        if attr.path().is_ident("diesel") {
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("column_name") {
+                    let value = meta.value()?;
+                    if let Ok(lit) = value.parse::<syn::LitStr>() {
+                        column_name = Some(lit.value());
+                    } else {
+                        let ident: syn::Ident = value.parse()?;
+                        column_name = Some(ident.to_string());
+                    }
+                } else if meta.path.is_ident("sql_type") {
+                    let value = meta.value()?;
+                    let ty: syn::Path = value.parse()?;
+                    sql_type = Some(ty.to_token_stream().to_string());
+                } else if meta.input.peek(syn::token::Paren) {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    let _ = content.parse::<proc_macro2::TokenStream>();
+                }
+                Ok(())
+            });
+        }
     }
 
     Ok(RustField {
@@ -90,97 +161,300 @@ fn extract_field_info(field: &Field) -> Result<RustField, String> {
         field_type,
         is_optional,
         attributes,
+        column_name,
+        sql_type,
+        type_info,
     })
 }
 
-fn extract_type_info(ty: &Type) -> Result<(String, bool), String> {
+/// A recursively-resolved type, e.g. `Vec<String>` becomes
+/// `{ base: "Vec", generics: [{ base: "String", ... }], is_collection: true }`
+/// instead of collapsing to the bare `"Vec"` the old single-segment-only
+/// extractor produced.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TypeInfo {
+    pub base: String,
+    pub generics: Vec<TypeInfo>,
+    pub is_optional: bool,
+    pub is_collection: bool,
+}
+
+const COLLECTION_TYPES: &[&str] = &["Vec", "HashMap", "BTreeMap", "HashSet", "BTreeSet", "VecDeque"];
+
+fn extract_type_info(ty: &Type) -> Result<TypeInfo, String> {
     match ty {
         Type::Path(type_path) => {
             let path = &type_path.path;
-            if path.segments.len() == 1 {
-                let segment = &path.segments[0];
-                let ident = segment.ident.to_string();
-
-                // Check for Option<T>
-                if ident == "Option" {
-                    if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
-                        if args.args.len() == 1 {
-                            if let syn::GenericArgument::Type(inner_type) = &args.args[0] {
-                                let (inner_type_str, _) = extract_type_info(inner_type)?;
-                                return Ok((inner_type_str, true));
-                            }
-                        }
-                    }
+            let segment = path.segments.last().ok_or_else(|| "Empty type path".to_string())?;
+            let ident = segment.ident.to_string();
+
+            let generics: Vec<TypeInfo> = match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args) => args
+                    .args
+                    .iter()
+                    .filter_map(|arg| match arg {
+                        syn::GenericArgument::Type(inner) => extract_type_info(inner).ok(),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            };
+
+            // Option<T> unwraps to T with `is_optional` set, same as before.
+            if ident == "Option" {
+                if let Some(inner) = generics.first().cloned() {
+                    return Ok(TypeInfo { is_optional: true, ..inner });
                 }
+            }
 
-                Ok((ident, false))
-            } else {
-                // Handle multi-segment paths like std::collections::HashMap
-                let full_path = path.segments
+            let base = if path.segments.len() > 1 {
+                path.segments
                     .iter()
                     .map(|seg| seg.ident.to_string())
                     .collect::<Vec<_>>()
-                    .join("::");
-                Ok((full_path, false))
+                    .join("::")
+            } else {
+                ident.clone()
+            };
+
+            Ok(TypeInfo {
+                is_collection: COLLECTION_TYPES.contains(&ident.as_str()),
+                base,
+                generics,
+                is_optional: false,
+            })
+        }
+        Type::Array(_) => Ok(unit_type_info("Array", true)),
+        Type::Slice(_) => Ok(unit_type_info("Slice", true)),
+        Type::Ptr(_) => Ok(unit_type_info("Ptr", false)),
+        Type::Reference(_) => Ok(unit_type_info("Reference", false)),
+        Type::Tuple(_) => Ok(unit_type_info("Tuple", false)),
+        _ => Ok(unit_type_info("Unknown", false)),
+    }
+}
+
+fn unit_type_info(base: &str, is_collection: bool) -> TypeInfo {
+    TypeInfo {
+        base: base.to_string(),
+        generics: Vec::new(),
+        is_optional: false,
+        is_collection,
+    }
+}
+
+/// Whether any `#[derive(...)]` on `attrs` names `target`.
+fn has_derive(attrs: &[syn::Attribute], target: &str) -> bool {
+    attrs.iter().any(|attr| {
+        if !attr.path().is_ident("derive") {
+            return false;
+        }
+        let mut found = false;
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(target) {
+                found = true;
             }
+            Ok(())
+        });
+        found
+    })
+}
+
+/// A single-field tuple struct deriving `DieselNewType` (the
+/// `diesel_derive_newtype` pattern, e.g. `pub struct PostId(pub i32);`) maps
+/// one Rust wrapper type to the underlying column type. Returns `None` for
+/// any struct that doesn't match that shape so the caller falls back to
+/// `extract_struct_info`.
+fn extract_newtype_info(struct_item: &ItemStruct) -> Result<Option<RustNewType>, String> {
+    let unnamed = match &struct_item.fields {
+        Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => unnamed,
+        _ => return Ok(None),
+    };
+    if !has_derive(&struct_item.attrs, "DieselNewType") {
+        return Ok(None);
+    }
+
+    let inner_type = extract_type_info(&unnamed.unnamed[0].ty)?.base;
+    let mut derives = Vec::new();
+    for attr in &struct_item.attrs {
+        if attr.path().is_ident("derive") {
+            let _ = attr.parse_nested_meta(|meta| {
+                if let Some(ident) = meta.path.get_ident() {
+                    derives.push(ident.to_string());
+                }
+                Ok(())
+            });
         }
-        Type::Array(_) => Ok(("Array".to_string(), false)),
-        Type::Slice(_) => Ok(("Slice".to_string(), false)),
-        Type::Ptr(_) => Ok(("Ptr".to_string(), false)),
-        Type::Reference(_) => Ok(("Reference".to_string(), false)),
-        Type::Tuple(_) => Ok(("Tuple".to_string(), false)),
-        _ => Ok(("Unknown".to_string(), false)),
     }
+
+    Ok(Some(RustNewType {
+        name: struct_item.ident.to_string(),
+        inner_type,
+        derives,
+    }))
 }
 
-fn extract_diesel_derives(struct_item: &ItemStruct) -> Option<DieselDerive> {
+/// Extract a `RustEnum` from a top-level `enum` item (the status-column
+/// pattern used throughout this crate's models). Non-unit variants can't be
+/// represented as a Postgres enum/CHECK constraint, so they're flagged in
+/// `diagnostics` and skipped rather than aborting the whole file.
+fn extract_enum_info(enum_item: &syn::ItemEnum, diagnostics: &mut Vec<String>) -> RustEnum {
+    let name = enum_item.ident.to_string();
+    let attributes = enum_item
+        .attrs
+        .iter()
+        .map(|attr| attr.to_token_stream().to_string())
+        .collect();
+
+    let mut variants = Vec::new();
+    for variant in &enum_item.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            diagnostics.push(format!(
+                "{name}: variant {} carries data and can't map to a Postgres enum/CHECK value; skipped",
+                variant.ident
+            ));
+            continue;
+        }
+
+        let discriminant = variant
+            .discriminant
+            .as_ref()
+            .map(|(_, expr)| expr.to_token_stream().to_string());
+
+        let mut serde_rename = None;
+        for attr in &variant.attrs {
+            if attr.path().is_ident("serde") {
+                let _ = attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("rename") {
+                        let value = meta.value()?;
+                        let lit: syn::LitStr = value.parse()?;
+                        serde_rename = Some(lit.value());
+                    }
+                    Ok(())
+                });
+            }
+        }
+
+        variants.push(RustEnumVariant {
+            ident: variant.ident.to_string(),
+            discriminant: discriminant.or(serde_rename),
+        });
+    }
+
+    RustEnum {
+        name,
+        variants,
+        attributes,
+    }
+}
+
+/// Walk a struct's attributes with syn's typed meta API rather than
+/// string-matching the stringified tokens, so formatting differences
+/// (`# [derive` vs `#[derive`), grouped derives, and the modern
+/// `#[diesel(table_name = ...)]` form are all handled precisely. Unknown or
+/// malformed `#[diesel(...)]` content is recorded in `diagnostics` instead of
+/// aborting the whole file.
+fn extract_diesel_derives(struct_item: &ItemStruct, diagnostics: &mut Vec<String>) -> Option<DieselDerive> {
     let name = struct_item.ident.to_string();
     let mut derives = Vec::new();
     let mut associations = Vec::new();
+    let mut table_name = None;
+    let mut belongs_to = Vec::new();
+    let mut primary_key = Vec::new();
 
-    // Parse attributes by converting to string and searching
     for attr in &struct_item.attrs {
-        let attr_str = attr.to_token_stream().to_string();
-
-        // Check for derive macros
-        if attr_str.contains("# [derive") || attr_str.contains("#[derive") {
-            // Extract derive names
-            if attr_str.contains("Queryable") {
-                derives.push("Queryable".to_string());
-            }
-            if attr_str.contains("Insertable") {
-                derives.push("Insertable".to_string());
-            }
-            if attr_str.contains("AsChangeset") {
-                derives.push("AsChangeset".to_string());
-            }
-            if attr_str.contains("Associations") {
-                derives.push("Associations".to_string());
+        if attr.path().is_ident("derive") {
+            let result = attr.parse_nested_meta(|meta| {
+                if let Some(ident) = meta.path.get_ident() {
+                    derives.push(ident.to_string());
+                }
+                Ok(())
+            });
+            if let Err(e) = result {
+                diagnostics.push(format!("{name}: malformed #[derive(...)] list: {e}"));
             }
-            if attr_str.contains("Identifiable") {
-                derives.push("Identifiable".to_string());
+        } else if attr.path().is_ident("diesel") {
+            let result = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("table_name") {
+                    let value = meta.value()?;
+                    if let Ok(lit) = value.parse::<syn::LitStr>() {
+                        table_name = Some(lit.value());
+                    } else {
+                        let path: syn::Path = value.parse()?;
+                        table_name = Some(path.to_token_stream().to_string());
+                    }
+                } else if meta.path.is_ident("belongs_to") {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    let parent: syn::Path = content.parse()?;
+                    let mut foreign_key = None;
+                    while content.peek(syn::Token![,]) {
+                        content.parse::<syn::Token![,]>()?;
+                        let key: syn::Ident = content.parse()?;
+                        content.parse::<syn::Token![=]>()?;
+                        let value: syn::Ident = content.parse()?;
+                        if key == "foreign_key" {
+                            foreign_key = Some(value.to_string());
+                        }
+                    }
+                    belongs_to.push(BelongsTo {
+                        parent: parent.to_token_stream().to_string(),
+                        foreign_key,
+                    });
+                } else if meta.path.is_ident("primary_key") {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    loop {
+                        let ident: syn::Ident = content.parse()?;
+                        primary_key.push(ident.to_string());
+                        if content.peek(syn::Token![,]) {
+                            content.parse::<syn::Token![,]>()?;
+                        } else {
+                            break;
+                        }
+                    }
+                } else if meta.input.peek(syn::token::Paren) {
+                    // Any other grouped `#[diesel(...)]` content we don't
+                    // specifically model yet; flag it, and consume it so it
+                    // doesn't break parsing of the attributes that follow.
+                    diagnostics.push(format!(
+                        "{name}: unrecognized #[diesel({})] key; skipped",
+                        meta.path.to_token_stream()
+                    ));
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    let _ = content.parse::<proc_macro2::TokenStream>();
+                }
+                Ok(())
+            });
+            if let Err(e) = result {
+                diagnostics.push(format!("{name}: unrecognized #[diesel(...)] attribute: {e}"));
             }
-        }
-
-        // Check for table_name
-        if attr_str.contains("# [table_name") || attr_str.contains("#[table_name") {
-            // Extract table name from = "..." pattern
-            if let Some(start) = attr_str.find("= \"") {
-                if let Some(end) = attr_str[start + 3..].find('"') {
-                    let table_name = attr_str[start + 3..start + 3 + end].to_string();
-                    associations.push(table_name);
+        } else if attr.path().is_ident("table_name") {
+            // Legacy Diesel 1.x form: `#[table_name = "foo"]`.
+            if let syn::Meta::NameValue(nv) = &attr.meta {
+                if let syn::Expr::Lit(expr_lit) = &nv.value {
+                    if let syn::Lit::Str(s) = &expr_lit.lit {
+                        table_name = Some(s.value());
+                    }
                 }
             }
         }
     }
 
-    if derives.is_empty() && associations.is_empty() {
+    if let Some(t) = &table_name {
+        associations.push(t.clone());
+    }
+
+    if derives.is_empty() && associations.is_empty() && belongs_to.is_empty() && primary_key.is_empty() {
         None
     } else {
         Some(DieselDerive {
             struct_name: name,
             derives,
             associations,
+            table_name,
+            belongs_to,
+            primary_key,
         })
     }
 }
@@ -287,23 +561,62 @@ fn parse_column_def(def: &str) -> Option<(String, String, bool)> {
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args: Vec<String> = env::args().collect();
 
-    if args.len() != 2 {
-        eprintln!("Usage: {} <rust_file>", args[0]);
+    let usage = format!(
+        "Usage: {} <rust_file> [--emit ids|associations|status-sql|soft-delete|entity-macro|ltree|sql-types|pg-enums|allow-tables|join-tables|models|audit|spatial|ranges|status-text|dummy|seed|dto|entity-trait] [--type-map <path>] [--single-group]",
+        args.first().map(String::as_str).unwrap_or("specql")
+    );
+
+    if args.len() < 2 {
+        eprintln!("{usage}");
         std::process::exit(1);
     }
 
     let file_path = &args[1];
+    let mut emit_target: Option<String> = None;
+    let mut type_map_path: Option<String> = None;
+    let mut single_group = false;
+    let mut rest = args[2..].iter();
+    while let Some(flag) = rest.next() {
+        match flag.as_str() {
+            "--emit" => emit_target = rest.next().cloned(),
+            "--type-map" => type_map_path = rest.next().cloned(),
+            "--single-group" => single_group = true,
+            _ => {
+                eprintln!("{usage}");
+                std::process::exit(1);
+            }
+        }
+    }
+    let emit_target = emit_target.as_deref();
+    let type_map = match &type_map_path {
+        Some(path) => codegen::type_map::TypeMap::from_json(&fs::read_to_string(path)?)?,
+        None => codegen::type_map::TypeMap::default(),
+    };
     let source_code = fs::read_to_string(file_path)?;
 
     match syn::parse_file(&source_code) {
         Ok(syntax) => {
             let mut structs = Vec::new();
+            let mut newtypes = Vec::new();
+            let mut enums = Vec::new();
             let mut diesel_tables = Vec::new();
             let mut diesel_derives = Vec::new();
+            let mut diagnostics = Vec::new();
 
             for item in syntax.items {
                 match item {
                     syn::Item::Struct(struct_item) => {
+                        match extract_newtype_info(&struct_item) {
+                            Ok(Some(newtype)) => {
+                                newtypes.push(newtype);
+                                continue;
+                            }
+                            Ok(None) => {}
+                            Err(e) => {
+                                eprintln!("Failed to parse struct: {}", e);
+                                std::process::exit(1);
+                            }
+                        }
                         match extract_struct_info(&struct_item) {
                             Ok(rust_struct) => structs.push(rust_struct),
                             Err(e) => {
@@ -312,7 +625,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                             }
                         }
                         // Extract Diesel derives for this struct
-                        if let Some(derive_info) = extract_diesel_derives(&struct_item) {
+                        if let Some(derive_info) = extract_diesel_derives(&struct_item, &mut diagnostics) {
                             diesel_derives.push(derive_info);
                         }
                     }
@@ -321,15 +634,422 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                             diesel_tables.push(table);
                         }
                     }
+                    syn::Item::Enum(enum_item) => {
+                        enums.push(extract_enum_info(&enum_item, &mut diagnostics));
+                    }
                     _ => {} // Ignore other items
                 }
             }
 
-            // Output structs, diesel_tables, and diesel_derives
+            if emit_target == Some("allow-tables") {
+                let tables: Vec<String> = diesel_tables.iter().map(|t| t.name.clone()).collect();
+                let edges = codegen::components::discover_fk_edges(&diesel_tables, &diesel_derives);
+                println!(
+                    "{}",
+                    codegen::allow_tables::render_allow_tables_by_component(&tables, &edges, single_group)
+                );
+                return Ok(());
+            }
+
+            if emit_target == Some("join-tables") {
+                for table in &diesel_tables {
+                    if let Some(edges) = codegen::join_tables::discover_join_table_edges(table) {
+                        println!("{}", codegen::join_tables::render_composite_table_header(table));
+                        println!("{}", codegen::join_tables::render_join_table_edges(&edges));
+                    }
+                }
+                return Ok(());
+            }
+
+            if emit_target == Some("audit") {
+                let columns = codegen::audit::AuditColumns::default();
+                let timestamp_ty = codegen::models::sql_type_to_rust(&columns.timestamp_sql_type, false);
+                println!("{}", codegen::audit::render_audit_table_columns(&columns));
+                println!("{}", codegen::audit::render_audited_trait(&timestamp_ty));
+                println!("{}", codegen::audit::render_auditable_trait());
+                for table in &diesel_tables {
+                    if codegen::audit::supports_audit(table, &columns) {
+                        let struct_name = codegen::models::table_to_struct_name(&table.name);
+                        println!("{}", codegen::audit::render_audited_impl(&struct_name, &columns, &timestamp_ty));
+                        println!("{}", codegen::audit::render_auditable_impl(&struct_name, &columns));
+                    }
+                }
+                return Ok(());
+            }
+
+            if emit_target == Some("spatial") {
+                for table in &diesel_tables {
+                    let columns: Vec<(&str, &str)> = table
+                        .columns
+                        .iter()
+                        .map(|c| (c.name.as_str(), c.sql_type.as_str()))
+                        .collect();
+                    if codegen::geometry::table_needs_geometry_import(&columns) {
+                        println!("-- {}", table.name);
+                        print!("{}", codegen::geometry::render_geometry_import());
+                        for (name, sql_type) in &columns {
+                            if codegen::geometry::is_spatial_sql_type(sql_type) {
+                                println!("    {name} -> Nullable<{sql_type}>,");
+                            }
+                        }
+                    }
+                }
+                return Ok(());
+            }
+
+            if emit_target == Some("ranges") {
+                let mut emitted = std::collections::HashSet::new();
+                for table in &diesel_tables {
+                    for column in &table.columns {
+                        if codegen::ranges::is_range_sql_type(&column.sql_type) && emitted.insert(column.sql_type.clone()) {
+                            if let Some(operators) = codegen::ranges::render_range_operators(&column.sql_type) {
+                                println!("{operators}");
+                            }
+                        }
+                    }
+                }
+                return Ok(());
+            }
+
+            if emit_target == Some("models") {
+                println!("{}", codegen::models::render_full_feature_imports());
+                let known_ids = codegen::ids::generate_id_newtypes_from_tables(&diesel_tables);
+                for id_type in &known_ids {
+                    println!("{}", codegen::ids::render_id_newtype(id_type));
+                }
+                for table in &diesel_tables {
+                    let relations = codegen::models::discover_table_belongs_to(table);
+                    println!("{}", codegen::models::render_row_struct(table, &relations, &known_ids));
+                    println!("{}", codegen::models::render_new_row_struct(table, &known_ids));
+                    println!("{}", codegen::models::render_changeset_struct(table, &known_ids));
+                }
+                return Ok(());
+            }
+
+            if emit_target == Some("entity-trait") {
+                println!("{}", codegen::entity::render_entity_trait());
+                println!("{}", codegen::soft_delete::render_soft_delete_trait());
+                println!("{}", codegen::entity::render_persisted_trait());
+                println!("{}", codegen::entity::render_repository());
+                for s in &structs {
+                    if s.name.starts_with("New") || !s.fields.iter().any(|f| f.name == "id") {
+                        continue;
+                    }
+                    let has_status = s.fields.iter().any(|f| f.name == "status");
+                    println!("{}", codegen::entity::render_entity_impl(&s.name, has_status));
+                    let table = codegen::inflect::pluralize(&to_snake_case(&s.name));
+                    // `Persisted::soft_delete`'s default body requires
+                    // `Self: SoftDelete<Id = Self::Id>`, so a model only
+                    // gets the generic repository layer once it already has
+                    // the `SoftDelete` impl `--emit soft-delete` generates.
+                    if codegen::soft_delete::supports_soft_delete(s) {
+                        println!("{}", codegen::soft_delete::render_soft_delete_impl(s, &table));
+                        println!("{}", codegen::entity::render_persisted_impl(&s.name, &table));
+                    }
+                }
+                return Ok(());
+            }
+
+            if emit_target == Some("dto") {
+                let known_ids = codegen::ids::generate_id_newtypes_from_tables(&diesel_tables);
+                for table in &diesel_tables {
+                    let relations = codegen::models::discover_table_belongs_to(table);
+                    println!("{}", codegen::dto::render_dto_struct(table, &relations, &known_ids));
+                    println!("{}", codegen::dto::render_dto_from_impl(table, &relations));
+                }
+                return Ok(());
+            }
+
+            if emit_target == Some("dummy") {
+                println!("{}", codegen::dummy::render_dummy_derive_attr());
+                for s in &structs {
+                    println!("-- {}", s.name);
+                    for f in &s.fields {
+                        let sql_type = type_map.resolve(&f.type_info);
+                        let column = DieselColumn { name: f.name.clone(), sql_type, is_nullable: f.is_optional };
+                        let status_variants: Option<Vec<String>> = enums
+                            .iter()
+                            .find(|e| e.name == f.field_type)
+                            .map(|e| e.variants.iter().map(|v| v.ident.clone()).collect());
+                        if let Some(attr) = codegen::dummy::render_dummy_field_attr(&column, status_variants.as_deref()) {
+                            print!("{attr}");
+                        }
+                    }
+                }
+                return Ok(());
+            }
+
+            if emit_target == Some("seed") {
+                println!("{}", codegen::seed::render_seedable_trait());
+                for table in &diesel_tables {
+                    let struct_name = codegen::models::table_to_struct_name(&table.name);
+                    println!("{}", codegen::seed::render_seedable_impl(&struct_name, &table.name));
+                }
+                let edges = codegen::components::discover_fk_edges(&diesel_tables, &diesel_derives);
+                let table_names: Vec<String> = diesel_tables.iter().map(|t| t.name.clone()).collect();
+                let ordered_struct_names: Vec<String> = codegen::seed::topological_table_order(&table_names, &edges)
+                    .iter()
+                    .map(|table| codegen::models::table_to_struct_name(table))
+                    .collect();
+                println!("{}", codegen::seed::render_seed_all(&ordered_struct_names));
+                return Ok(());
+            }
+
+            if emit_target == Some("pg-enums") {
+                let status_enums: Vec<&RustEnum> =
+                    enums.iter().filter(|e| e.name.ends_with("Status")).collect();
+                let markers: Vec<(String, String)> = status_enums
+                    .iter()
+                    .map(|e| (e.name.clone(), to_snake_case(&e.name)))
+                    .collect();
+                println!("{}", codegen::sql_types_mod::render_sql_types_module(&markers));
+                for rust_enum in &status_enums {
+                    let pg_type_name = to_snake_case(&rust_enum.name);
+                    let (use_line, column_line) =
+                        codegen::sql_types_mod::render_enum_column("status", &rust_enum.name);
+                    println!("{use_line}\n{column_line}\n");
+                    let variant_refs: Vec<&str> =
+                        rust_enum.variants.iter().map(|v| v.ident.as_str()).collect();
+                    println!(
+                        "{}",
+                        codegen::status_enum::render_status_to_from_sql(&rust_enum.name, &variant_refs)
+                    );
+                    println!("-- or, with diesel-derive-enum:");
+                    println!("{}", codegen::status_enum::render_db_enum_derive(&rust_enum.name));
+                    println!(
+                        "-- {}",
+                        codegen::status_enum::render_create_type_migration(&pg_type_name, &variant_refs)
+                    );
+                }
+                return Ok(());
+            }
+
+            if emit_target == Some("sql-types") {
+                for s in &structs {
+                    println!("-- {}", s.name);
+                    for f in &s.fields {
+                        let sql_type = type_map.resolve(&f.type_info);
+                        let sql_type = if f.is_optional { format!("Nullable<{sql_type}>") } else { sql_type };
+                        println!("{}: {}", f.name, sql_type);
+                    }
+                }
+                return Ok(());
+            }
+
+            if emit_target == Some("ids") {
+                let id_types = codegen::ids::generate_id_newtypes(&structs);
+                for id_type in &id_types {
+                    println!("{}", codegen::ids::render_id_newtype(id_type));
+                }
+                for s in &structs {
+                    println!("{}", codegen::ids::render_retyped_struct(s, &id_types));
+                }
+                return Ok(());
+            }
+
+            if emit_target == Some("status-sql") {
+                for rust_enum in &enums {
+                    let sql_type_name = to_snake_case(&rust_enum.name);
+                    let variant_refs: Vec<&str> =
+                        rust_enum.variants.iter().map(|v| v.ident.as_str()).collect();
+                    println!(
+                        "{}",
+                        codegen::status_enum::render_status_sql_impls(&rust_enum.name, &sql_type_name, &variant_refs)
+                    );
+                    println!(
+                        "-- {}",
+                        codegen::status_enum::render_create_type_migration(&sql_type_name, &variant_refs)
+                    );
+                }
+                return Ok(());
+            }
+
+            if emit_target == Some("status-text") {
+                for rust_enum in &enums {
+                    let variant_refs: Vec<&str> =
+                        rust_enum.variants.iter().map(|v| v.ident.as_str()).collect();
+                    println!(
+                        "{}",
+                        codegen::status_enum::render_text_backed_status_impls(&rust_enum.name, &variant_refs)
+                    );
+                }
+                return Ok(());
+            }
+
+            if emit_target == Some("ltree") {
+                let has_ltree_column = diesel_tables
+                    .iter()
+                    .any(|t| t.columns.iter().any(|c| codegen::ltree::is_ltree_column(&c.sql_type)));
+                let has_self_referential_struct = structs.iter().any(|s| {
+                    !s.name.starts_with("New")
+                        && codegen::associations::self_referential(s, &codegen::associations::discover_belongs_to(s))
+                });
+                let edges_preview = codegen::components::discover_fk_edges(&diesel_tables, &diesel_derives);
+                let parent_of_preview: std::collections::HashMap<String, String> = edges_preview.into_iter().collect();
+                let has_multi_hop_preview = diesel_tables
+                    .iter()
+                    .any(|t| codegen::ltree::is_multi_hop_hierarchy(&t.name, &parent_of_preview));
+                if has_ltree_column {
+                    println!(
+                        "{}",
+                        codegen::sql_types_mod::wrap_in_sql_types_module(&[codegen::ltree::render_ltree_sql_type().to_string()])
+                    );
+                    println!("{}", codegen::ltree::render_ltree_operators());
+                }
+                if has_ltree_column || has_self_referential_struct || has_multi_hop_preview {
+                    println!("{}", codegen::ltree::render_ltree_sql_functions());
+                }
+                for table in &diesel_tables {
+                    if !table.columns.iter().any(|c| codegen::ltree::is_ltree_column(&c.sql_type)) {
+                        continue;
+                    }
+                    let struct_name = codegen::models::table_to_struct_name(&table.name);
+                    println!("{}", codegen::ltree::render_hierarchy_helpers(&struct_name, &table.name));
+                    for stmt in codegen::ltree::render_ltree_migration(&table.name) {
+                        println!("-- {}", stmt);
+                    }
+                }
+
+                // Hierarchy support also targets flat taxonomy-style structs
+                // (e.g. a `Category` with a self-referential `parent_id`)
+                // that don't yet have a generated `path` column; a real
+                // spec-driven generator would read this off a relationship
+                // annotation instead.
+                for s in &structs {
+                    if s.name.starts_with("New") {
+                        continue;
+                    }
+                    let relations = codegen::associations::discover_belongs_to(s);
+                    if codegen::associations::self_referential(s, &relations) {
+                        let table = codegen::inflect::pluralize(&to_snake_case(&s.name));
+                        println!("{}", codegen::ltree::render_hierarchy_helpers(&s.name, &table));
+                        for stmt in codegen::ltree::render_ltree_migration(&table) {
+                            println!("-- {}", stmt);
+                        }
+                    }
+                }
+
+                // Tables whose FK chains back at themselves (entity07_id on
+                // entity07s, ...) are parent chains that'd otherwise need
+                // recursive self-joins; suggest the materialized-path column.
+                for table in &diesel_tables {
+                    if table.columns.iter().any(|c| codegen::ltree::is_ltree_column(&c.sql_type)) {
+                        continue;
+                    }
+                    if let Some(fk_column) = codegen::ltree::parent_chain_column(table) {
+                        println!(
+                            "-- {} has a parent chain via {fk_column}; consider a materialized `path: Ltree` column:",
+                            table.name,
+                        );
+                        for stmt in codegen::ltree::render_materialize_path_migration(&table.name) {
+                            println!("-- {}", stmt);
+                        }
+                    }
+                }
+
+                // Tables at least two FK hops deep (entity47s -> entity06s ->
+                // entity05s) get the full Hierarchical subsystem: ancestors/
+                // descendants/subtree query builders plus the re-parenting
+                // statement needed when a row moves to a new parent.
+                let edges = codegen::components::discover_fk_edges(&diesel_tables, &diesel_derives);
+                let parent_of: std::collections::HashMap<String, String> = edges.into_iter().collect();
+                let has_multi_hop = diesel_tables
+                    .iter()
+                    .any(|t| codegen::ltree::is_multi_hop_hierarchy(&t.name, &parent_of));
+                if has_multi_hop {
+                    println!("{}", codegen::ltree::render_hierarchical_trait());
+                }
+                for table in &diesel_tables {
+                    if !codegen::ltree::is_multi_hop_hierarchy(&table.name, &parent_of) {
+                        continue;
+                    }
+                    let struct_name = codegen::models::table_to_struct_name(&table.name);
+                    println!("{}", codegen::ltree::render_hierarchical_impl(&struct_name, &table.name));
+                    println!("-- {}", codegen::ltree::render_reparent_statement(&table.name));
+                }
+                return Ok(());
+            }
+
+            if emit_target == Some("entity-macro") {
+                println!("{}", codegen::entity_macro::render_macro_definition());
+                for s in &structs {
+                    if s.name.starts_with("New") {
+                        continue;
+                    }
+                    let status_name = format!("{}Status", s.name);
+                    let status_enum = match enums.iter().find(|e| e.name == status_name) {
+                        Some(e) => e,
+                        None => continue,
+                    };
+                    let table = codegen::inflect::pluralize(&to_snake_case(&s.name));
+                    let columns: Vec<(&str, &str)> = s
+                        .fields
+                        .iter()
+                        .filter(|f| f.name != "id" && f.name != "status")
+                        .map(|f| (f.name.as_str(), f.field_type.as_str()))
+                        .collect();
+                    let variant_refs: Vec<&str> =
+                        status_enum.variants.iter().map(|v| v.ident.as_str()).collect();
+                    println!(
+                        "{}",
+                        codegen::entity_macro::render_invocation(&s.name, &table, &columns, &variant_refs)
+                    );
+                }
+                return Ok(());
+            }
+
+            if emit_target == Some("soft-delete") {
+                println!("{}", codegen::soft_delete::render_soft_delete_trait());
+                for s in &structs {
+                    if codegen::soft_delete::supports_soft_delete(s) {
+                        let table = codegen::inflect::pluralize(&to_snake_case(&s.name));
+                        println!("{}", codegen::soft_delete::render_soft_delete_impl(s, &table));
+                    }
+                }
+                return Ok(());
+            }
+
+            if emit_target == Some("associations") {
+                let table_name_of: std::collections::HashMap<&str, &str> = diesel_derives
+                    .iter()
+                    .filter_map(|d| d.table_name.as_deref().map(|t| (d.struct_name.as_str(), t)))
+                    .collect();
+
+                for s in &structs {
+                    let relations = codegen::associations::discover_belongs_to(s);
+                    let header = codegen::associations::render_associations_header(&relations);
+                    if !header.is_empty() {
+                        println!("{}", header);
+                    }
+
+                    let Some(table) = table_name_of.get(s.name.as_str()) else { continue };
+                    if codegen::associations::self_referential(s, &relations) {
+                        println!("{}", codegen::associations::render_self_join_alias(table));
+                    } else if codegen::associations::composite_foreign_key(&relations) {
+                        if let Some(parent_table) = relations.first().and_then(|r| table_name_of.get(r.parent.as_str())) {
+                            let columns: Vec<String> = relations.iter().map(|r| r.foreign_key.clone()).collect();
+                            println!("{}", codegen::associations::render_composite_join_helper(table, parent_table, &columns));
+                        }
+                    } else {
+                        for relation in &relations {
+                            if let Some(parent_table) = table_name_of.get(relation.parent.as_str()) {
+                                println!("joinable!({table} -> {parent_table} ({}));", relation.foreign_key);
+                            }
+                        }
+                    }
+                }
+                return Ok(());
+            }
+
+            // Output structs, diesel_tables, diesel_derives, and any
+            // non-fatal diagnostics collected while walking attributes.
             let output = serde_json::json!({
                 "structs": structs,
+                "newtypes": newtypes,
+                "enums": enums,
                 "diesel_tables": diesel_tables,
-                "diesel_derives": diesel_derives
+                "diesel_derives": diesel_derives,
+                "diagnostics": diagnostics
             });
             println!("{}", serde_json::to_string(&output)?);
             Ok(())
@@ -339,4 +1059,19 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             std::process::exit(1);
         }
     }
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut out = String::new();
+    for (i, ch) in name.chars().enumerate() {
+        if ch.is_uppercase() {
+            if i != 0 {
+                out.push('_');
+            }
+            out.extend(ch.to_lowercase());
+        } else {
+            out.push(ch);
+        }
+    }
+    out
 }
\ No newline at end of file